@@ -97,6 +97,26 @@ impl Database {
         suggestions
     }
     
+    pub fn get_all_lemmas(&self) -> Result<Vec<String>> {
+        debug!("Loading all distinct lemmas");
+
+        let conn = self.connection.lock().unwrap();
+        let mut stmt = conn.prepare_cached(
+            "SELECT DISTINCT lemma FROM dictionary_entries"
+        )?;
+
+        let lemmas: Result<Vec<String>> = stmt.query_map([], |row| {
+            Ok(row.get(0)?)
+        })?.collect();
+
+        match lemmas {
+            Ok(ref results) => debug!("Loaded {} distinct lemmas", results.len()),
+            Err(ref e) => error!("Error loading lemmas: {}", e),
+        }
+
+        lemmas
+    }
+
     pub fn get_stats(&self) -> Result<DatabaseStats> {
         debug!("Getting database statistics");
         
@@ -1,7 +1,8 @@
 // Prevents additional console window on Windows in release, DO NOT REMOVE!!
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use log::{info, error};
+use log::{info, warn, error};
+use std::collections::VecDeque;
 use std::path::PathBuf;
 use std::sync::Mutex;
 use tauri::{State, Manager, WindowEvent, menu::{Menu, MenuItem}, tray::{TrayIconBuilder, MouseButton}};
@@ -11,14 +12,27 @@ mod database;
 mod search_engine;
 mod plugin_manager;
 mod logger;
+mod window_state;
+mod settings;
+mod search_history;
+mod shortcuts;
+mod plugin_watcher;
 
-use search_engine::{SearchEngine, SearchEngineStats};
-use plugin_manager::{PluginManager, PluginInfo, PluginManagerStats};
+use std::collections::HashMap;
+use search_engine::{SearchEngine, SearchEngineStats, SearchMode};
+use plugin_manager::{PluginManager, PluginInfo, PluginManagerStats, AvailableUpdate};
+use window_state::StateFlags;
+use shortcuts::ShortcutAction;
+use tauri_plugin_global_shortcut::Shortcut;
 
 // Application state
 struct AppState {
     search_engine: Mutex<Option<SearchEngine>>,
     plugin_manager: Mutex<Option<PluginManager>>,
+    search_history: Mutex<VecDeque<String>>,
+    shortcuts: Mutex<HashMap<ShortcutAction, String>>,
+    // Keeps the plugin filesystem watcher alive while hot-reload is enabled.
+    plugin_watcher: Mutex<Option<notify::RecommendedWatcher>>,
 }
 
 // Tauri commands
@@ -70,6 +84,13 @@ async fn initialize_search_engine(app: tauri::AppHandle, state: State<'_, AppSta
     
     match SearchEngine::new(db_path, inflection_path) {
         Ok(engine) => {
+            // Hand a dictionary handle to the plugin manager (if it is already
+            // up) so plugins can resolve words through the `lookup_word` ABI.
+            if let Ok(mut pm_guard) = state.plugin_manager.lock() {
+                if let Some(manager) = pm_guard.as_mut() {
+                    manager.set_dictionary(engine.clone());
+                }
+            }
             let mut search_engine = state.search_engine.lock().unwrap();
             *search_engine = Some(engine);
             info!("Search engine initialized successfully");
@@ -84,13 +105,19 @@ async fn initialize_search_engine(app: tauri::AppHandle, state: State<'_, AppSta
 }
 
 #[tauri::command]
-async fn search_dictionary(term: String, state: State<'_, AppState>) -> Result<Vec<database::DictionaryEntry>, String> {
+async fn search_dictionary(term: String, mode: Option<SearchMode>, state: State<'_, AppState>) -> Result<Vec<database::DictionaryEntry>, String> {
+    let mode = mode.unwrap_or_default();
     let search_engine_guard = state.search_engine.lock().unwrap();
-    
+
     match search_engine_guard.as_ref() {
         Some(engine) => {
-            match engine.search(&term) {
-                Ok(results) => Ok(results.entries),
+            match engine.search_with_mode(&term, mode) {
+                Ok(results) => {
+                    // Record the query in the recent-search ring buffer.
+                    let mut history = state.search_history.lock().unwrap();
+                    search_history::record(&mut history, &term);
+                    Ok(results.entries)
+                }
                 Err(e) => {
                     let error_msg = format!("Search failed for '{}': {}", term, e);
                     error!("{}", error_msg);
@@ -102,6 +129,20 @@ async fn search_dictionary(term: String, state: State<'_, AppState>) -> Result<V
     }
 }
 
+#[tauri::command]
+async fn get_search_history(state: State<'_, AppState>) -> Result<Vec<String>, String> {
+    let history = state.search_history.lock().unwrap();
+    Ok(history.iter().cloned().collect())
+}
+
+#[tauri::command]
+async fn clear_search_history(state: State<'_, AppState>) -> Result<(), String> {
+    let mut history = state.search_history.lock().unwrap();
+    history.clear();
+    info!("Cleared search history");
+    Ok(())
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 struct Suggestion {
     word: String,
@@ -158,9 +199,12 @@ async fn get_search_stats(state: State<'_, AppState>) -> Result<SearchEngineStat
 async fn initialize_plugin_manager(app: tauri::AppHandle, state: State<'_, AppState>) -> Result<String, String> {
     info!("Initializing plugin manager...");
     
-    // Try to get resource directory for production builds
-    let plugins_dir = match app.path().resource_dir() {
-        Ok(resource_dir) => resource_dir.join("plugins"),
+    // Built-in plugins ship read-only with the app (resource dir); user-installed
+    // plugins live in the writable app data directory.
+    let builtin_dir = app.path().resource_dir().ok().map(|dir| dir.join("plugins"));
+
+    let plugins_dir = match app.path().app_data_dir() {
+        Ok(data_dir) => data_dir.join("plugins"),
         Err(_) => {
             // Fallback for development - go up from target/debug to project root
             let current_dir = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
@@ -172,11 +216,19 @@ async fn initialize_plugin_manager(app: tauri::AppHandle, state: State<'_, AppSt
             project_root.join("plugins")
         }
     };
-    
-    info!("Looking for plugins at: {:?}", plugins_dir);
-    
-    match PluginManager::new(plugins_dir) {
-        Ok(manager) => {
+
+    info!("Looking for user plugins at: {:?}", plugins_dir);
+    info!("Looking for built-in plugins at: {:?}", builtin_dir);
+
+    match PluginManager::new(plugins_dir, builtin_dir) {
+        Ok(mut manager) => {
+            // If the search engine is already initialized, give the manager a
+            // dictionary handle up front so plugins loaded now can look up words.
+            if let Ok(engine_guard) = state.search_engine.lock() {
+                if let Some(engine) = engine_guard.as_ref() {
+                    manager.set_dictionary(engine.clone());
+                }
+            }
             let stats = manager.get_stats();
             let mut plugin_manager = state.plugin_manager.lock().unwrap();
             *plugin_manager = Some(manager);
@@ -229,12 +281,32 @@ async fn enable_plugin(id: String, state: State<'_, AppState>) -> Result<(), Str
     }
 }
 
+#[tauri::command]
+async fn load_plugin(id: String, state: State<'_, AppState>) -> Result<(), String> {
+    let mut plugin_manager_guard = state.plugin_manager.lock().unwrap();
+
+    match plugin_manager_guard.as_mut() {
+        Some(manager) => manager.load_plugin(&id),
+        None => Err("Plugin manager not initialized".to_string()),
+    }
+}
+
+#[tauri::command]
+async fn unload_plugin(id: String, state: State<'_, AppState>) -> Result<(), String> {
+    let mut plugin_manager_guard = state.plugin_manager.lock().unwrap();
+
+    match plugin_manager_guard.as_mut() {
+        Some(manager) => manager.unload_plugin(&id),
+        None => Err("Plugin manager not initialized".to_string()),
+    }
+}
+
 #[tauri::command]
 async fn disable_plugin(id: String, state: State<'_, AppState>) -> Result<(), String> {
     let mut plugin_manager_guard = state.plugin_manager.lock().unwrap();
     
     match plugin_manager_guard.as_mut() {
-        Some(manager) => manager.disable_plugin(&id),
+        Some(manager) => manager.disable_plugin(&id).map_err(|e| e.to_string()),
         None => Err("Plugin manager not initialized".to_string()),
     }
 }
@@ -244,7 +316,91 @@ async fn uninstall_plugin(id: String, state: State<'_, AppState>) -> Result<(),
     let mut plugin_manager_guard = state.plugin_manager.lock().unwrap();
     
     match plugin_manager_guard.as_mut() {
-        Some(manager) => manager.uninstall_plugin(&id),
+        Some(manager) => manager.uninstall_plugin(&id).map_err(|e| e.to_string()),
+        None => Err("Plugin manager not initialized".to_string()),
+    }
+}
+
+#[tauri::command]
+async fn install_plugin_from_repository(id: String, version: String, state: State<'_, AppState>) -> Result<String, String> {
+    let mut plugin_manager_guard = state.plugin_manager.lock().unwrap();
+
+    match plugin_manager_guard.as_mut() {
+        Some(manager) => manager.install_plugin_from_repository(&id, &version),
+        None => Err("Plugin manager not initialized".to_string()),
+    }
+}
+
+#[tauri::command]
+async fn set_plugin_repository_config(
+    url: Option<String>,
+    public_key: String,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let mut plugin_manager_guard = state.plugin_manager.lock().unwrap();
+
+    match plugin_manager_guard.as_mut() {
+        Some(manager) => {
+            if let Some(url) = url {
+                manager.set_repository_url(url);
+            }
+            manager.set_repository_public_key(public_key);
+            Ok(())
+        }
+        None => Err("Plugin manager not initialized".to_string()),
+    }
+}
+
+#[tauri::command]
+async fn check_for_updates(state: State<'_, AppState>) -> Result<Vec<AvailableUpdate>, String> {
+    let plugin_manager_guard = state.plugin_manager.lock().unwrap();
+
+    match plugin_manager_guard.as_ref() {
+        Some(manager) => manager.check_for_updates(),
+        None => Err("Plugin manager not initialized".to_string()),
+    }
+}
+
+#[tauri::command]
+async fn update_plugin(id: String, state: State<'_, AppState>) -> Result<(), String> {
+    let mut plugin_manager_guard = state.plugin_manager.lock().unwrap();
+
+    match plugin_manager_guard.as_mut() {
+        Some(manager) => manager.update_plugin(&id),
+        None => Err("Plugin manager not initialized".to_string()),
+    }
+}
+
+#[tauri::command]
+async fn start_plugin_watch(app: tauri::AppHandle, state: State<'_, AppState>) -> Result<(), String> {
+    let watcher = plugin_watcher::start(app.clone())?;
+    *state.plugin_watcher.lock().unwrap() = Some(watcher);
+    Ok(())
+}
+
+#[tauri::command]
+async fn stop_plugin_watch(state: State<'_, AppState>) -> Result<(), String> {
+    // Dropping the watcher stops the background thread.
+    *state.plugin_watcher.lock().unwrap() = None;
+    Ok(())
+}
+
+#[tauri::command]
+async fn transform_plugin_result(id: String, input: String, state: State<'_, AppState>) -> Result<String, String> {
+    let mut plugin_manager_guard = state.plugin_manager.lock().unwrap();
+
+    match plugin_manager_guard.as_mut() {
+        Some(manager) => manager.transform_result(&id, &input),
+        None => Err("Plugin manager not initialized".to_string()),
+    }
+}
+
+#[tauri::command]
+async fn resolve_plugin_load_order(state: State<'_, AppState>) -> Result<Vec<String>, String> {
+    let plugin_manager_guard = state.plugin_manager.lock().unwrap();
+
+    match plugin_manager_guard.as_ref() {
+        Some(manager) => manager.resolve_load_order().map_err(|e| e.to_string()),
         None => Err("Plugin manager not initialized".to_string()),
     }
 }
@@ -268,8 +424,12 @@ async fn toggle_window(app: tauri::AppHandle) -> Result<(), String> {
                     window.hide().map_err(|e| e.to_string())?;
                 }
                 Ok(false) => {
-                    // Position window near cursor before showing
-                    position_window_near_cursor_sync(&window)?;
+                    // Anchor to the cursor only when no saved position exists, so
+                    // a position the user dragged the window to isn't clobbered.
+                    if !window_state::has_saved_position(&window.app_handle()) {
+                        position_window_near_cursor_sync(&window)?;
+                    }
+                    apply_visible_on_all_workspaces(&app, &window);
                     window.show().map_err(|e| e.to_string())?;
                     window.set_focus().map_err(|e| e.to_string())?;
                 }
@@ -306,6 +466,14 @@ async fn quit_app(app: tauri::AppHandle, state: State<'_, AppState>) -> Result<(
         }
     }
     
+    // Persist the recent-query history so it survives across sessions
+    if let Ok(history) = state.search_history.lock() {
+        match search_history::save(&app, &history) {
+            Ok(_) => info!("Search history persisted ({} entries)", history.len()),
+            Err(e) => error!("Failed to persist search history: {}", e),
+        }
+    }
+
     // Clean up plugin manager
     if let Ok(mut plugin_manager_guard) = state.plugin_manager.lock() {
         if let Some(_manager) = plugin_manager_guard.take() {
@@ -321,48 +489,158 @@ async fn quit_app(app: tauri::AppHandle, state: State<'_, AppState>) -> Result<(
     Ok(())
 }
 
-fn position_window_near_cursor_sync(window: &tauri::WebviewWindow) -> Result<(), String> {
-    #[cfg(windows)]
-    {
-        use winapi::um::winuser::{GetCursorPos, GetSystemMetrics, SM_CXSCREEN, SM_CYSCREEN};
-        use winapi::shared::windef::POINT;
-        
-        unsafe {
-            let mut cursor_pos = POINT { x: 0, y: 0 };
-            if GetCursorPos(&mut cursor_pos) != 0 {
-                let screen_width = GetSystemMetrics(SM_CXSCREEN);
-                let screen_height = GetSystemMetrics(SM_CYSCREEN);
-                
-                // Window dimensions (from config)
-                let window_width = 800;
-                let window_height = 600;
-                
-                // Calculate position near cursor with offset
-                let offset = 50;
-                let mut x = cursor_pos.x + offset;
-                let mut y = cursor_pos.y + offset;
-                
-                // Ensure window stays on screen
-                if x + window_width > screen_width {
-                    x = cursor_pos.x - window_width - offset;
-                }
-                if y + window_height > screen_height {
-                    y = cursor_pos.y - window_height - offset;
-                }
-                
-                // Ensure window doesn't go off screen on the left/top
-                if x < 0 { x = 10; }
-                if y < 0 { y = 10; }
-                
-                let position = tauri::PhysicalPosition::new(x, y);
-                window.set_position(position).map_err(|e| e.to_string())?;
-                return Ok(());
+#[tauri::command]
+async fn get_shortcuts(state: State<'_, AppState>) -> Result<HashMap<ShortcutAction, String>, String> {
+    let bindings = state.shortcuts.lock().unwrap();
+    Ok(bindings.clone())
+}
+
+#[tauri::command]
+async fn set_shortcut(app: tauri::AppHandle, action: ShortcutAction, accelerator: String, state: State<'_, AppState>) -> Result<(), String> {
+    // Parse the new accelerator up front so an invalid string is rejected
+    // before we touch the currently registered binding.
+    let new_shortcut: Shortcut = accelerator
+        .parse()
+        .map_err(|e| format!("Invalid accelerator '{}': {}", accelerator, e))?;
+
+    let old_accelerator = {
+        let bindings = state.shortcuts.lock().unwrap();
+        bindings.get(&action).cloned()
+    };
+
+    // Unregister the previous binding for this action, if any.
+    if let Some(old) = old_accelerator {
+        if let Ok(old_shortcut) = old.parse::<Shortcut>() {
+            if let Err(e) = app.global_shortcut().unregister(old_shortcut) {
+                warn!("Failed to unregister old shortcut '{}': {}", old, e);
             }
         }
     }
-    
-    // Fallback to center if cursor positioning fails or not on Windows
-    window.center().map_err(|e| e.to_string())?;
+
+    app.global_shortcut()
+        .register(new_shortcut)
+        .map_err(|e| format!("Failed to register shortcut '{}': {}", accelerator, e))?;
+
+    // Update the in-memory map and persist the full configuration.
+    let config = {
+        let mut bindings = state.shortcuts.lock().unwrap();
+        bindings.insert(action, accelerator.clone());
+        shortcuts::ShortcutConfig { bindings: bindings.clone() }
+    };
+    shortcuts::save(&app, &config)?;
+
+    info!("Set shortcut {:?} = {}", action, accelerator);
+    Ok(())
+}
+
+#[tauri::command]
+async fn set_visible_on_all_workspaces(app: tauri::AppHandle, enabled: bool) -> Result<(), String> {
+    let window = app
+        .get_webview_window("main")
+        .ok_or_else(|| "Main window not found".to_string())?;
+
+    window
+        .set_visible_on_all_workspaces(enabled)
+        .map_err(|e| e.to_string())?;
+
+    // Persist the preference so it is reapplied on the next launch.
+    let mut app_settings = settings::load(&app)?;
+    app_settings.visible_on_all_workspaces = enabled;
+    settings::save(&app, &app_settings)?;
+
+    info!("Set visible_on_all_workspaces = {}", enabled);
+    Ok(())
+}
+
+#[tauri::command]
+async fn save_window_state(app: tauri::AppHandle, flags: u32) -> Result<(), String> {
+    window_state::save_window_state(&app, StateFlags::from_bits_truncate(flags))
+}
+
+#[tauri::command]
+async fn restore_window_state(app: tauri::AppHandle, flags: u32) -> Result<bool, String> {
+    window_state::restore_window_state(&app, StateFlags::from_bits_truncate(flags))
+}
+
+/// Reapply the persisted "visible on all workspaces" preference to `window`.
+///
+/// Doing this as part of the toggle path guarantees the popup surfaces on the
+/// workspace the user is currently looking at, not the one it was last shown on.
+fn apply_visible_on_all_workspaces(app: &tauri::AppHandle, window: &tauri::WebviewWindow) {
+    match settings::load(app) {
+        Ok(app_settings) if app_settings.visible_on_all_workspaces => {
+            if let Err(e) = window.set_visible_on_all_workspaces(true) {
+                warn!("Failed to set visible_on_all_workspaces: {}", e);
+            }
+        }
+        Ok(_) => {}
+        Err(e) => warn!("Failed to load settings: {}", e),
+    }
+}
+
+/// Position the window near the cursor on whichever monitor the cursor is on.
+///
+/// This works identically on Windows, macOS, and Linux by querying the
+/// runtime's cursor and monitor APIs rather than the Win32 metrics: it finds
+/// the monitor under the cursor (so multi-display setups place the popup on the
+/// right screen), offsets the window by the user-configured gap, and clamps the
+/// result to that monitor's bounds so the window never spills off-screen.
+fn position_window_near_cursor_sync(window: &tauri::WebviewWindow) -> Result<(), String> {
+    let offset = settings::load(window.app_handle())
+        .map(|s| s.cursor_offset)
+        .unwrap_or(50);
+
+    let cursor = window.cursor_position().map_err(|e| e.to_string())?;
+
+    // Resolve the monitor under the cursor, falling back to the primary one.
+    let monitor = match window.monitor_from_point(cursor.x, cursor.y) {
+        Ok(Some(monitor)) => Some(monitor),
+        _ => window.primary_monitor().map_err(|e| e.to_string())?,
+    };
+
+    let monitor = match monitor {
+        Some(monitor) => monitor,
+        None => {
+            // No monitor information available - center as a last resort.
+            window.center().map_err(|e| e.to_string())?;
+            return Ok(());
+        }
+    };
+
+    let m_pos = monitor.position();
+    let m_size = monitor.size();
+    let win_size = window.outer_size().map_err(|e| e.to_string())?;
+
+    let cursor_x = cursor.x as i32;
+    let cursor_y = cursor.y as i32;
+    let win_w = win_size.width as i32;
+    let win_h = win_size.height as i32;
+
+    // Visible bounds of the monitor under the cursor.
+    let left = m_pos.x;
+    let top = m_pos.y;
+    let right = m_pos.x + m_size.width as i32;
+    let bottom = m_pos.y + m_size.height as i32;
+
+    let mut x = cursor_x + offset;
+    let mut y = cursor_y + offset;
+
+    // Flip to the other side of the cursor if the window would overflow.
+    if x + win_w > right {
+        x = cursor_x - win_w - offset;
+    }
+    if y + win_h > bottom {
+        y = cursor_y - win_h - offset;
+    }
+
+    // Clamp to the monitor's visible area.
+    x = x.clamp(left, (right - win_w).max(left));
+    y = y.clamp(top, (bottom - win_h).max(top));
+
+    window
+        .set_position(tauri::PhysicalPosition::new(x, y))
+        .map_err(|e| e.to_string())?;
+
     Ok(())
 }
 
@@ -392,6 +670,9 @@ fn main() {
     let app_state = AppState {
         search_engine: Mutex::new(None),
         plugin_manager: Mutex::new(None),
+        search_history: Mutex::new(VecDeque::new()),
+        shortcuts: Mutex::new(HashMap::new()),
+        plugin_watcher: Mutex::new(None),
     };
 
     tauri::Builder::default()
@@ -399,24 +680,38 @@ fn main() {
             tauri_plugin_global_shortcut::Builder::new()
                 .with_handler(|_app, shortcut, event| {
                     use tauri_plugin_global_shortcut::ShortcutState;
-                    if event.state == ShortcutState::Pressed {
-                        // Check which shortcut was pressed
-                        let key_code = shortcut.key;
-                        let modifiers = shortcut.mods;
-                        
-                        if format!("{:?}", key_code).contains("KeyQ") && 
-                           format!("{:?}", modifiers).contains("CONTROL") && 
-                           format!("{:?}", modifiers).contains("SHIFT") {
-                            // Ctrl+Shift+Q - quit app
-                            info!("Quit hotkey pressed (Ctrl+Shift+Q)");
+                    if event.state != ShortcutState::Pressed {
+                        return;
+                    }
+
+                    // Resolve which logical action the pressed shortcut is bound to
+                    // by comparing against the registered accelerator map.
+                    let action = {
+                        let state = _app.state::<AppState>();
+                        let bindings = state.shortcuts.lock().unwrap();
+                        bindings.iter().find_map(|(action, accel)| {
+                            accel
+                                .parse::<Shortcut>()
+                                .ok()
+                                .filter(|bound| bound == shortcut)
+                                .map(|_| *action)
+                        })
+                    };
+
+                    match action {
+                        Some(ShortcutAction::QuitApp) => {
+                            info!("Quit hotkey pressed");
                             _app.exit(0);
-                        } else {
-                            // Ctrl+Alt+D - toggle window
+                        }
+                        Some(ShortcutAction::ToggleWindow) => {
                             if let Some(window) = _app.get_webview_window("main") {
                                 match window.is_visible() {
                                     Ok(true) => { let _ = window.hide(); }
-                                    Ok(false) => { 
-                                        let _ = position_window_near_cursor_sync(&window);
+                                    Ok(false) => {
+                                        if !window_state::has_saved_position(&window.app_handle()) {
+                                            let _ = position_window_near_cursor_sync(&window);
+                                        }
+                                        apply_visible_on_all_workspaces(_app, &window);
                                         let _ = window.show();
                                         let _ = window.set_focus();
                                     }
@@ -424,6 +719,7 @@ fn main() {
                                 }
                             }
                         }
+                        None => {}
                     }
                 })
                 .build()
@@ -437,18 +733,31 @@ fn main() {
             
             info!("Dictionary App starting up with centralized logging...");
             
-            // Register global shortcut
-            use tauri_plugin_global_shortcut::{Code, Modifiers};
-            
-            // Register global shortcut for show/hide
-            app.global_shortcut().register(
-                tauri_plugin_global_shortcut::Shortcut::new(Some(Modifiers::CONTROL | Modifiers::ALT), Code::KeyD)
-            )?;
-            
-            // Register global shortcut for quit (Ctrl+Shift+Q)  
-            app.global_shortcut().register(
-                tauri_plugin_global_shortcut::Shortcut::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::KeyQ)
-            )?;
+            // Load the user-configurable global shortcuts from disk and register
+            // each binding, caching the accelerator strings for the dispatcher.
+            let shortcut_config = shortcuts::load(app.app_handle())
+                .unwrap_or_else(|e| {
+                    warn!("Failed to load shortcut config, using defaults: {}", e);
+                    shortcuts::ShortcutConfig::default()
+                });
+
+            for (action, accelerator) in &shortcut_config.bindings {
+                match accelerator.parse::<Shortcut>() {
+                    Ok(shortcut) => {
+                        if let Err(e) = app.global_shortcut().register(shortcut) {
+                            warn!("Failed to register shortcut {:?} ({}): {}", action, accelerator, e);
+                        } else {
+                            info!("Registered shortcut {:?}: {}", action, accelerator);
+                        }
+                    }
+                    Err(e) => warn!("Invalid accelerator {:?} ({}): {}", action, accelerator, e),
+                }
+            }
+
+            {
+                let state = app.state::<AppState>();
+                *state.shortcuts.lock().unwrap() = shortcut_config.bindings;
+            }
 
             // Create system tray
             let show_item = MenuItem::with_id(app, "show", "Show Dictionary", true, None::<&str>)?;
@@ -465,7 +774,10 @@ fn main() {
                         "show" => {
                             info!("Show Dictionary requested from tray menu");
                             if let Some(window) = app.get_webview_window("main") {
-                                let _ = position_window_near_cursor_sync(&window);
+                                if !window_state::has_saved_position(&window.app_handle()) {
+                                    let _ = position_window_near_cursor_sync(&window);
+                                }
+                                apply_visible_on_all_workspaces(app, &window);
                                 let _ = window.show();
                                 let _ = window.set_focus();
                             }
@@ -492,8 +804,11 @@ fn main() {
                                         true => { 
                                             let _ = window.hide(); 
                                         }
-                                        false => { 
-                                            let _ = position_window_near_cursor_sync(&window);
+                                        false => {
+                                            if !window_state::has_saved_position(&window.app_handle()) {
+                                                let _ = position_window_near_cursor_sync(&window);
+                                            }
+                                            apply_visible_on_all_workspaces(app, &window);
                                             let _ = window.show();
                                             let _ = window.set_focus();
                                         }
@@ -509,18 +824,50 @@ fn main() {
                 })
                 .build(app)?;
 
+            // Restore the window geometry persisted from the previous session.
+            // When no saved state exists we keep the current layout and let the
+            // cursor-anchored placement take over the first time it is shown.
+            match window_state::restore_window_state(app.app_handle(), StateFlags::POSITION | StateFlags::SIZE | StateFlags::MAXIMIZED) {
+                Ok(true) => info!("Window geometry restored from previous session"),
+                Ok(false) => info!("No saved window geometry, using cursor placement"),
+                Err(e) => warn!("Failed to restore window geometry: {}", e),
+            }
+
+            // Restore the recent-query history saved at the previous shutdown.
+            match search_history::load(app.app_handle()) {
+                Ok(history) => {
+                    let state = app.state::<AppState>();
+                    *state.search_history.lock().unwrap() = history;
+                }
+                Err(e) => warn!("Failed to load search history: {}", e),
+            }
+
+            // Apply the persisted cross-workspace preference to the main window.
+            if let Some(window) = app.get_webview_window("main") {
+                apply_visible_on_all_workspaces(app.app_handle(), &window);
+            }
+
             // Hide window on startup
             if let Some(window) = app.get_webview_window("main") {
                 let _ = window.hide();
             }
             
-            info!("Global shortcut registered: Ctrl+Alt+D");
+            info!("Global shortcuts registered from configuration");
             info!("System tray created");
             info!("App setup complete");
             Ok(())
         })
         .on_window_event(|_window, event| match event {
+            WindowEvent::Moved(_) | WindowEvent::Resized(_) => {
+                // Persist the new geometry so the popup reopens where the user left it.
+                let _ = window_state::save_window_state(
+                    _window.app_handle(),
+                    StateFlags::POSITION | StateFlags::SIZE | StateFlags::MAXIMIZED,
+                );
+            }
             WindowEvent::CloseRequested { api, .. } => {
+                // Capture the final geometry (including visibility) before hiding.
+                let _ = window_state::save_window_state(_window.app_handle(), StateFlags::ALL);
                 // Check if user is holding Alt key to force close
                 #[cfg(windows)]
                 unsafe {
@@ -549,6 +896,8 @@ fn main() {
             greet,
             initialize_search_engine,
             search_dictionary,
+            get_search_history,
+            clear_search_history,
             get_suggestions,
             get_inflections,
             get_search_stats,
@@ -557,10 +906,25 @@ fn main() {
             get_plugin,
             enable_plugin,
             disable_plugin,
+            load_plugin,
+            unload_plugin,
             uninstall_plugin,
+            install_plugin_from_repository,
+            set_plugin_repository_config,
+            check_for_updates,
+            update_plugin,
+            start_plugin_watch,
+            stop_plugin_watch,
+            resolve_plugin_load_order,
+            transform_plugin_result,
             get_plugin_stats,
             toggle_window,
             hide_window,
+            set_visible_on_all_workspaces,
+            get_shortcuts,
+            set_shortcut,
+            save_window_state,
+            restore_window_state,
             quit_app,
             get_logs_info,
             get_logs_directory
@@ -1,8 +1,41 @@
+//! Plugin host and the extism ABI plugins are compiled against.
+//!
+//! Plugins are WebAssembly modules run through [extism](https://extism.org);
+//! there is no component-model/WIT interface, so this list is the real
+//! contract. Every host function is PTR-in/PTR-out (extism's host-call
+//! convention: a single memory handle holding UTF-8 bytes in, and for
+//! functions that return a value, a single memory handle out). Guest exports
+//! are detected at call time with `Plugin::function_exists` and take the same
+//! shape.
+//!
+//! Host functions (imported by the plugin, bound only when the matching
+//! `permissions` capability is present in the manifest):
+//! - `lookup_word(word: string) -> string` — always bound; look up a headword
+//!   and return its formatted definition as JSON.
+//! - `http_get(url: string) -> string` — capability `"network"`.
+//! - `fs_read(path: string) -> bytes` — capability `"fs-read"`; `path` is
+//!   resolved relative to, and sandboxed inside, the plugin's own directory.
+//! - `clipboard_write(text: string)` — capability `"clipboard"`.
+//!
+//! Guest exports (called by the host, both optional):
+//! - `activate()` — invoked once right after instantiation.
+//! - `transform_result(input: string) -> string` — applied to a formatted
+//!   result before it is displayed.
+
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
 use std::fs;
 use std::path::{Path, PathBuf};
-use log::{info, warn};
+use log::{info, warn, error};
+use semver::{Version, VersionReq};
+use sha2::{Digest, Sha256};
+
+/// Default remote repository the manager fetches its index from.
+const DEFAULT_REPOSITORY_URL: &str = "https://plugins.dictionary-app.example/index.json";
+use extism::{Plugin as WasmPlugin, PluginBuilder, Wasm, Function, PTR, CurrentPlugin, UserData, Val};
+
+use crate::search_engine::SearchEngine;
 
 // Plugin manifest structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,6 +49,36 @@ pub struct PluginManifest {
     pub permissions: Vec<String>,
     pub dependencies: HashMap<String, String>,
     pub enabled: bool,
+    // Inclusive lower / exclusive upper engine-version bounds the plugin was
+    // built against. Absent bounds are treated as unconstrained.
+    #[serde(default)]
+    pub min_engine_version: Option<String>,
+    #[serde(default)]
+    pub max_engine_version: Option<String>,
+    // Optional lifecycle hook scripts, mapping a hook name
+    // (preinstall/postinstall/preuninstall/postuninstall) to a script path
+    // relative to the plugin directory.
+    #[serde(default)]
+    pub scripts: HashMap<String, String>,
+}
+
+/// Engine (app) version the plugin host exposes to compatibility checks.
+pub const ENGINE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Where a discovered plugin came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PluginSource {
+    /// Shipped with the app in the read-only built-in directory.
+    BuiltIn,
+    /// Installed by the user into the writable plugins directory.
+    User,
+}
+
+impl Default for PluginSource {
+    fn default() -> Self {
+        PluginSource::User
+    }
 }
 
 // Plugin metadata for runtime
@@ -25,18 +88,156 @@ pub struct PluginInfo {
     pub path: PathBuf,
     pub loaded: bool,
     pub error: Option<String>,
+    // SHA-256 (hex) of the package archive this plugin was installed from, when
+    // it was obtained from the remote repository. Lets integrity be re-checked.
+    #[serde(default)]
+    pub checksum: Option<String>,
+    // Whether this plugin is a bundled built-in or a user install.
+    #[serde(default)]
+    pub source: PluginSource,
+}
+
+/// A signed listing of the plugins available from the remote repository.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositoryIndex {
+    pub plugins: Vec<RepositoryPlugin>,
+    /// Base64 ed25519 signature over the canonical JSON of `plugins`.
+    #[serde(default)]
+    pub signature: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositoryPlugin {
+    pub id: String,
+    pub name: String,
+    pub versions: Vec<RepositoryVersion>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositoryVersion {
+    pub version: String,
+    pub url: String,
+    pub sha256: String,
+    #[serde(default)]
+    pub min_engine_version: Option<String>,
+    #[serde(default)]
+    pub max_engine_version: Option<String>,
+}
+
+/// The outcome of hot-reloading a single plugin directory, emitted to the UI.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "id", rename_all = "snake_case")]
+pub enum PluginChange {
+    Added(String),
+    Updated(String),
+    Removed(String),
+    Errored(String),
+}
+
+/// An available upgrade reported by [`PluginManager::check_for_updates`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AvailableUpdate {
+    pub id: String,
+    pub current_version: String,
+    pub latest_version: String,
+}
+
+/// Errors surfaced by dependency resolution and lifecycle operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PluginError {
+    /// No plugin with the given id is installed.
+    NotFound(String),
+    /// The dependency graph contains a cycle; carries the unresolved nodes.
+    CircularDependency(Vec<String>),
+    /// A declared dependency is not installed.
+    MissingDependency { plugin: String, dependency: String },
+    /// A dependency is installed but its version does not satisfy the requirement.
+    VersionMismatch {
+        plugin: String,
+        dependency: String,
+        required: String,
+        found: String,
+    },
+    /// A declared dependency is installed but disabled, so it would never load.
+    DisabledDependency { plugin: String, dependency: String },
+    /// The plugin cannot be removed/disabled because other enabled plugins depend on it.
+    InUseBy(String, Vec<String>),
+    /// Any other failure, carrying a human-readable message.
+    Other(String),
+}
+
+impl fmt::Display for PluginError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PluginError::NotFound(id) => write!(f, "Plugin not found: {}", id),
+            PluginError::CircularDependency(nodes) => {
+                write!(f, "Circular dependency detected among: {}", nodes.join(", "))
+            }
+            PluginError::MissingDependency { plugin, dependency } => {
+                write!(f, "Plugin '{}' requires missing dependency '{}'", plugin, dependency)
+            }
+            PluginError::VersionMismatch { plugin, dependency, required, found } => write!(
+                f,
+                "Plugin '{}' requires '{}' {} but installed version is {}",
+                plugin, dependency, required, found
+            ),
+            PluginError::DisabledDependency { plugin, dependency } => write!(
+                f,
+                "Plugin '{}' requires '{}', which is installed but disabled",
+                plugin, dependency
+            ),
+            PluginError::InUseBy(id, dependents) => write!(
+                f,
+                "Plugin '{}' is still in use by: {}",
+                id,
+                dependents.join(", ")
+            ),
+            PluginError::Other(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for PluginError {}
+
+impl From<PluginError> for String {
+    fn from(err: PluginError) -> String {
+        err.to_string()
+    }
 }
 
 // Plugin manager state
-#[derive(Debug)]
 pub struct PluginManager {
     plugins: HashMap<String, PluginInfo>,
     plugins_dir: PathBuf,
+    // Instantiated WebAssembly modules, keyed by plugin id. Only populated for
+    // plugins that have been successfully loaded via `load_plugin`.
+    instances: HashMap<String, WasmPlugin>,
+    // URL the signed repository index is fetched from.
+    repository_url: String,
+    // Base64 ed25519 public key the index signature is verified against.
+    // `None` until a real key is configured, in which case signature
+    // verification fails loudly instead of against a placeholder key.
+    repository_public_key: Option<String>,
+    // Read-only directory of plugins shipped with the app, if any.
+    builtin_dir: Option<PathBuf>,
+    // Dictionary handle exposed to plugins through the `lookup_word` host ABI.
+    // `None` until the search engine is initialized and handed over.
+    dictionary: Option<SearchEngine>,
+}
+
+/// Services the capability-gated host functions reach into on behalf of a
+/// sandboxed plugin. Cloned into each bound host function's user data.
+#[derive(Clone)]
+struct HostContext {
+    /// Dictionary handle backing the `lookup_word` ABI, when available.
+    dictionary: Option<SearchEngine>,
+    /// The plugin's own directory, used to sandbox `fs_read` paths.
+    plugin_dir: PathBuf,
 }
 
 impl PluginManager {
-    pub fn new(plugins_dir: PathBuf) -> Result<Self, String> {
-        // Ensure plugins directory exists
+    pub fn new(plugins_dir: PathBuf, builtin_dir: Option<PathBuf>) -> Result<Self, String> {
+        // Ensure the writable plugins directory exists
         if !plugins_dir.exists() {
             fs::create_dir_all(&plugins_dir)
                 .map_err(|e| format!("Failed to create plugins directory: {}", e))?;
@@ -46,19 +247,58 @@ impl PluginManager {
         let mut manager = PluginManager {
             plugins: HashMap::new(),
             plugins_dir,
+            instances: HashMap::new(),
+            repository_url: DEFAULT_REPOSITORY_URL.to_string(),
+            repository_public_key: REPOSITORY_PUBLIC_KEY.map(str::to_string),
+            builtin_dir,
+            dictionary: None,
         };
 
         // Discover plugins on initialization
         manager.discover_plugins()?;
-        
+
         Ok(manager)
     }
 
-    /// Discover all plugins in the plugins directory
+    /// Discover all plugins across the built-in and user directories.
+    ///
+    /// The built-in directory is scanned first; the user directory is scanned
+    /// second so that a user-installed plugin with the same `id` overrides the
+    /// bundled copy (allowing users to upgrade or patch a shipped plugin).
     pub fn discover_plugins(&mut self) -> Result<(), String> {
-        info!("Discovering plugins in: {:?}", self.plugins_dir);
-        
-        let entries = fs::read_dir(&self.plugins_dir)
+        self.plugins.clear();
+
+        if let Some(builtin_dir) = self.builtin_dir.clone() {
+            if builtin_dir.exists() {
+                self.discover_in(&builtin_dir, PluginSource::BuiltIn)?;
+            }
+        }
+
+        let user_dir = self.plugins_dir.clone();
+        self.discover_in(&user_dir, PluginSource::User)?;
+
+        // Built-in plugins live in a read-only directory, so their enabled
+        // flag cannot be persisted next to them. Apply any user override
+        // recorded in the writable plugins directory on top of the shipped
+        // default.
+        let overrides = self.load_overrides();
+        for (id, enabled) in overrides {
+            if let Some(plugin) = self.plugins.get_mut(&id) {
+                if plugin.source == PluginSource::BuiltIn {
+                    plugin.manifest.enabled = enabled;
+                }
+            }
+        }
+
+        info!("Discovered {} plugins", self.plugins.len());
+        Ok(())
+    }
+
+    /// Scan a single plugin root, tagging each discovered plugin with `source`.
+    fn discover_in(&mut self, dir: &Path, source: PluginSource) -> Result<(), String> {
+        info!("Discovering {:?} plugins in: {:?}", source, dir);
+
+        let entries = fs::read_dir(dir)
             .map_err(|e| format!("Failed to read plugins directory: {}", e))?;
 
         for entry in entries {
@@ -68,15 +308,31 @@ impl PluginManager {
             if path.is_dir() {
                 match self.load_plugin_manifest(&path) {
                     Ok(manifest) => {
+                        // Mark engine-incompatible plugins rather than loading them.
+                        let error = match Self::check_compatibility(&manifest) {
+                            Ok(()) => None,
+                            Err(msg) => {
+                                warn!("Plugin '{}' incompatible: {}", manifest.id, msg);
+                                Some(msg)
+                            }
+                        };
+
+                        let overrides = self.plugins.contains_key(&manifest.id);
                         let plugin_info = PluginInfo {
                             manifest: manifest.clone(),
                             path: path.clone(),
                             loaded: false,
-                            error: None,
+                            error,
+                            checksum: None,
+                            source,
                         };
-                        
+
                         self.plugins.insert(manifest.id.clone(), plugin_info);
-                        info!("Discovered plugin: {} v{}", manifest.name, manifest.version);
+                        if overrides {
+                            info!("Plugin '{}' overridden by {:?} copy", manifest.id, source);
+                        } else {
+                            info!("Discovered plugin: {} v{}", manifest.name, manifest.version);
+                        }
                     }
                     Err(e) => {
                         warn!("Failed to load plugin from {:?}: {}", path, e);
@@ -85,7 +341,6 @@ impl PluginManager {
             }
         }
 
-        info!("Discovered {} plugins", self.plugins.len());
         Ok(())
     }
 
@@ -130,6 +385,43 @@ impl PluginManager {
         Ok(manifest)
     }
 
+    /// Check a manifest's declared engine bounds against the running engine.
+    ///
+    /// Returns a descriptive error when the current [`ENGINE_VERSION`] falls
+    /// outside `[min_engine_version, max_engine_version)`. Used both during
+    /// discovery and up front on the install path to refuse incompatible plugins.
+    pub fn check_compatibility(manifest: &PluginManifest) -> Result<(), String> {
+        let engine = Version::parse(ENGINE_VERSION)
+            .map_err(|e| format!("Invalid engine version '{}': {}", ENGINE_VERSION, e))?;
+
+        let describe = |manifest: &PluginManifest| {
+            format!(
+                "requires engine >={},<{} but running {}",
+                manifest.min_engine_version.as_deref().unwrap_or("0.0.0"),
+                manifest.max_engine_version.as_deref().unwrap_or("∞"),
+                ENGINE_VERSION
+            )
+        };
+
+        if let Some(min) = &manifest.min_engine_version {
+            let min = Version::parse(min)
+                .map_err(|e| format!("Invalid min_engine_version '{}': {}", min, e))?;
+            if engine < min {
+                return Err(describe(manifest));
+            }
+        }
+
+        if let Some(max) = &manifest.max_engine_version {
+            let max = Version::parse(max)
+                .map_err(|e| format!("Invalid max_engine_version '{}': {}", max, e))?;
+            if engine >= max {
+                return Err(describe(manifest));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Get all discovered plugins
     pub fn get_plugins(&self) -> Vec<&PluginInfo> {
         self.plugins.values().collect()
@@ -140,31 +432,361 @@ impl PluginManager {
         self.plugins.get(id)
     }
 
-    /// Enable a plugin
+    /// Enable a plugin.
+    ///
+    /// Enabling a plugin pulls it into the dependency graph, so its declared
+    /// dependencies are validated first: the enable is refused (and the flag
+    /// left untouched) if a dependency is missing, version-incompatible, or
+    /// would introduce a cycle. This mirrors the check `load_plugin` performs
+    /// before instantiation.
     pub fn enable_plugin(&mut self, id: &str) -> Result<(), String> {
-        if let Some(plugin) = self.plugins.get_mut(id) {
-            plugin.manifest.enabled = true;
-            let plugin_clone = plugin.clone();
-            self.save_plugin_manifest(&plugin_clone)?;
-            info!("Enabled plugin: {}", id);
-            Ok(())
-        } else {
-            Err(format!("Plugin not found: {}", id))
+        if !self.plugins.contains_key(id) {
+            return Err(format!("Plugin not found: {}", id));
+        }
+
+        // Tentatively flip the flag, validate the resulting graph, and revert
+        // if the plugin cannot be satisfied.
+        let previously = self.plugins.get(id).unwrap().manifest.enabled;
+        self.plugins.get_mut(id).unwrap().manifest.enabled = true;
+        if let Err(e) = self.resolve_load_order() {
+            self.plugins.get_mut(id).unwrap().manifest.enabled = previously;
+            return Err(e.to_string());
+        }
+
+        let plugin_clone = self.plugins.get(id).unwrap().clone();
+        self.persist_enabled_state(&plugin_clone)?;
+        info!("Enabled plugin: {}", id);
+        Ok(())
+    }
+
+    /// Disable a plugin.
+    ///
+    /// Refuses to disable a plugin that other enabled plugins still depend on,
+    /// returning [`PluginError::InUseBy`] instead of silently breaking them.
+    pub fn disable_plugin(&mut self, id: &str) -> Result<(), PluginError> {
+        if !self.plugins.contains_key(id) {
+            return Err(PluginError::NotFound(id.to_string()));
+        }
+
+        let dependents = self.dependents_of(id);
+        if !dependents.is_empty() {
+            return Err(PluginError::InUseBy(id.to_string(), dependents));
+        }
+
+        let plugin = self.plugins.get_mut(id).unwrap();
+        plugin.manifest.enabled = false;
+        plugin.loaded = false;
+        let plugin_clone = plugin.clone();
+        self.persist_enabled_state(&plugin_clone)
+            .map_err(PluginError::Other)?;
+        // Drop any running instance so a disabled plugin stops executing.
+        self.instances.remove(id);
+        info!("Disabled plugin: {}", id);
+        Ok(())
+    }
+
+    /// Enabled plugins that declare a dependency on `id` (its reference holders).
+    fn dependents_of(&self, id: &str) -> Vec<String> {
+        let mut dependents: Vec<String> = self
+            .plugins
+            .values()
+            .filter(|p| p.manifest.enabled && p.manifest.dependencies.contains_key(id))
+            .map(|p| p.manifest.id.clone())
+            .collect();
+        dependents.sort();
+        dependents
+    }
+
+    /// Number of enabled plugins currently depending on `id`.
+    pub fn reference_count(&self, id: &str) -> usize {
+        self.dependents_of(id).len()
+    }
+
+    /// Resolve a topological load order so dependencies load before dependents.
+    ///
+    /// Implemented with Kahn's algorithm over the graph of enabled plugins.
+    /// Each declared dependency is first checked for existence, version
+    /// compatibility, and that it is itself enabled; a cycle surfaces as
+    /// [`PluginError::CircularDependency`].
+    pub fn resolve_load_order(&self) -> Result<Vec<String>, PluginError> {
+        let enabled: Vec<&PluginInfo> =
+            self.plugins.values().filter(|p| p.manifest.enabled).collect();
+        let enabled_ids: HashSet<String> =
+            enabled.iter().map(|p| p.manifest.id.clone()).collect();
+
+        let mut in_degree: HashMap<String, usize> =
+            enabled_ids.iter().map(|id| (id.clone(), 0)).collect();
+        let mut dependents: HashMap<String, Vec<String>> = HashMap::new();
+
+        for plugin in &enabled {
+            for (dep_id, req_str) in &plugin.manifest.dependencies {
+                let dep = self.plugins.get(dep_id).ok_or_else(|| {
+                    PluginError::MissingDependency {
+                        plugin: plugin.manifest.id.clone(),
+                        dependency: dep_id.clone(),
+                    }
+                })?;
+
+                let req = VersionReq::parse(req_str).map_err(|e| {
+                    PluginError::Other(format!(
+                        "Invalid version requirement '{}' for dependency '{}': {}",
+                        req_str, dep_id, e
+                    ))
+                })?;
+                let version = Version::parse(&dep.manifest.version).map_err(|e| {
+                    PluginError::Other(format!(
+                        "Invalid version '{}' for plugin '{}': {}",
+                        dep.manifest.version, dep_id, e
+                    ))
+                })?;
+                if !req.matches(&version) {
+                    return Err(PluginError::VersionMismatch {
+                        plugin: plugin.manifest.id.clone(),
+                        dependency: dep_id.clone(),
+                        required: req_str.clone(),
+                        found: dep.manifest.version.clone(),
+                    });
+                }
+
+                // A disabled dependency would never load, so an enabled plugin
+                // depending on one can never actually be satisfied at runtime.
+                if !enabled_ids.contains(dep_id) {
+                    return Err(PluginError::DisabledDependency {
+                        plugin: plugin.manifest.id.clone(),
+                        dependency: dep_id.clone(),
+                    });
+                }
+
+                *in_degree.get_mut(&plugin.manifest.id).unwrap() += 1;
+                dependents
+                    .entry(dep_id.clone())
+                    .or_default()
+                    .push(plugin.manifest.id.clone());
+            }
+        }
+
+        // Seed the queue with every zero-in-degree node, processed in a stable
+        // (sorted) order for deterministic output.
+        let mut ready: Vec<String> = in_degree
+            .iter()
+            .filter(|(_, degree)| **degree == 0)
+            .map(|(id, _)| id.clone())
+            .collect();
+        ready.sort();
+        ready.reverse(); // pop() yields the lexicographically smallest first
+
+        let mut order = Vec::with_capacity(enabled_ids.len());
+        while let Some(node) = ready.pop() {
+            order.push(node.clone());
+            if let Some(children) = dependents.get(&node) {
+                for child in children {
+                    let degree = in_degree.get_mut(child).unwrap();
+                    *degree -= 1;
+                    if *degree == 0 {
+                        ready.push(child.clone());
+                        ready.sort();
+                        ready.reverse();
+                    }
+                }
+            }
+        }
+
+        if order.len() < enabled_ids.len() {
+            // Whatever still has a non-zero in-degree is part of a cycle.
+            let mut remaining: Vec<String> = in_degree
+                .into_iter()
+                .filter(|(_, degree)| *degree > 0)
+                .map(|(id, _)| id)
+                .collect();
+            remaining.sort();
+            return Err(PluginError::CircularDependency(remaining));
+        }
+
+        Ok(order)
+    }
+
+    /// Load a plugin by instantiating its WebAssembly entry point.
+    ///
+    /// The module is sandboxed and only granted the host functions whose
+    /// capability string appears in the manifest's `permissions` list. On
+    /// success `PluginInfo.loaded` is flipped to `true`; any instantiation
+    /// failure is recorded in `PluginInfo.error` and returned.
+    pub fn load_plugin(&mut self, id: &str) -> Result<(), String> {
+        let (wasm_path, permissions, plugin_dir) = {
+            let plugin = self
+                .plugins
+                .get(id)
+                .ok_or_else(|| format!("Plugin not found: {}", id))?;
+            // Never instantiate a module built for a different engine generation.
+            Self::check_compatibility(&plugin.manifest)
+                .map_err(|msg| format!("Plugin '{}' {}", id, msg))?;
+            (
+                plugin.path.join(&plugin.manifest.main),
+                plugin.manifest.permissions.clone(),
+                plugin.path.clone(),
+            )
+        };
+
+        // Refuse to load a plugin whose dependency graph cannot be satisfied
+        // (missing dependency, version mismatch, or cycle).
+        self.resolve_load_order().map_err(|e| e.to_string())?;
+
+        // Sandbox `fs_read` to the plugin's own directory; fall back to the raw
+        // path if it cannot be canonicalized (e.g. symlink resolution fails).
+        let plugin_dir = plugin_dir.canonicalize().unwrap_or(plugin_dir);
+        let context = HostContext {
+            dictionary: self.dictionary.clone(),
+            plugin_dir,
+        };
+        let functions = Self::host_functions(&context, &permissions);
+        let wasm = Wasm::file(wasm_path);
+        let manifest = extism::Manifest::new([wasm]);
+
+        match PluginBuilder::new(manifest).with_functions(functions).build() {
+            Ok(mut instance) => {
+                // Invoke the guest's `activate` entry point if it exports one.
+                if instance.function_exists("activate") {
+                    if let Err(e) = instance.call::<&str, &str>("activate", "") {
+                        warn!("Plugin '{}' activate hook failed: {}", id, e);
+                    }
+                }
+                self.instances.insert(id.to_string(), instance);
+                if let Some(plugin) = self.plugins.get_mut(id) {
+                    plugin.loaded = true;
+                    plugin.error = None;
+                }
+                info!("Loaded plugin: {}", id);
+                Ok(())
+            }
+            Err(e) => {
+                let msg = format!("Failed to instantiate plugin '{}': {}", id, e);
+                error!("{}", msg);
+                if let Some(plugin) = self.plugins.get_mut(id) {
+                    plugin.loaded = false;
+                    plugin.error = Some(msg.clone());
+                }
+                Err(msg)
+            }
         }
     }
 
-    /// Disable a plugin
-    pub fn disable_plugin(&mut self, id: &str) -> Result<(), String> {
+    /// Unload a previously loaded plugin, dropping its WebAssembly instance.
+    pub fn unload_plugin(&mut self, id: &str) -> Result<(), String> {
+        if self.instances.remove(id).is_none() {
+            return Err(format!("Plugin not loaded: {}", id));
+        }
         if let Some(plugin) = self.plugins.get_mut(id) {
-            plugin.manifest.enabled = false;
             plugin.loaded = false;
-            let plugin_clone = plugin.clone();
-            self.save_plugin_manifest(&plugin_clone)?;
-            info!("Disabled plugin: {}", id);
-            Ok(())
-        } else {
-            Err(format!("Plugin not found: {}", id))
         }
+        info!("Unloaded plugin: {}", id);
+        Ok(())
+    }
+
+    /// Run a loaded plugin's optional `transform_result` guest export over a
+    /// formatted result string, returning the (possibly rewritten) output.
+    ///
+    /// A plugin that is not loaded, or that exports no transformer, leaves the
+    /// input untouched so the display path can call this unconditionally.
+    pub fn transform_result(&mut self, id: &str, input: &str) -> Result<String, String> {
+        let instance = match self.instances.get_mut(id) {
+            Some(instance) => instance,
+            None => return Ok(input.to_string()),
+        };
+        if !instance.function_exists("transform_result") {
+            return Ok(input.to_string());
+        }
+        instance
+            .call::<&str, &str>("transform_result", input)
+            .map(|s| s.to_string())
+            .map_err(|e| format!("Plugin '{}' transform_result failed: {}", id, e))
+    }
+
+    /// Build the capability-gated set of host functions for a plugin.
+    ///
+    /// `lookup_word` is the core dictionary ABI and is always bound; every other
+    /// host function is only bound when its capability string is present in
+    /// `permissions`, so a plugin cannot reach APIs it never declared. Each bound
+    /// function carries a clone of `context` so it can reach engine services.
+    fn host_functions(context: &HostContext, permissions: &[String]) -> Vec<Function> {
+        let mut functions = vec![Function::new(
+            "lookup_word",
+            [PTR],
+            [PTR],
+            UserData::new(context.clone()),
+            host_lookup_word,
+        )];
+
+        if permissions.iter().any(|p| p == "network") {
+            functions.push(Function::new(
+                "http_get",
+                [PTR],
+                [PTR],
+                UserData::new(context.clone()),
+                host_http_get,
+            ));
+        }
+
+        if permissions.iter().any(|p| p == "fs-read") {
+            functions.push(Function::new(
+                "fs_read",
+                [PTR],
+                [PTR],
+                UserData::new(context.clone()),
+                host_fs_read,
+            ));
+        }
+
+        if permissions.iter().any(|p| p == "clipboard") {
+            functions.push(Function::new(
+                "clipboard_write",
+                [PTR],
+                [],
+                UserData::new(context.clone()),
+                host_clipboard_write,
+            ));
+        }
+
+        functions
+    }
+
+    /// Persist a plugin's enabled flag to the appropriate location.
+    ///
+    /// User plugins own a writable `plugin.json`, so the manifest is rewritten
+    /// in place. Built-in plugins live in a read-only directory; their
+    /// enabled/disabled state is instead recorded as an override in the
+    /// writable plugins directory and re-applied at discovery.
+    fn persist_enabled_state(&self, plugin: &PluginInfo) -> Result<(), String> {
+        match plugin.source {
+            PluginSource::User => self.save_plugin_manifest(plugin),
+            PluginSource::BuiltIn => {
+                let mut overrides = self.load_overrides();
+                overrides.insert(plugin.manifest.id.clone(), plugin.manifest.enabled);
+                self.save_overrides(&overrides)
+            }
+        }
+    }
+
+    /// Path of the built-in enable/disable override file in the writable dir.
+    fn overrides_path(&self) -> PathBuf {
+        self.plugins_dir.join(".plugin-overrides.json")
+    }
+
+    /// Load the built-in override map, treating a missing/corrupt file as empty.
+    fn load_overrides(&self) -> HashMap<String, bool> {
+        let path = self.overrides_path();
+        match fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
+            Err(_) => HashMap::new(),
+        }
+    }
+
+    /// Write the built-in override map back to the writable plugins directory.
+    fn save_overrides(&self, overrides: &HashMap<String, bool>) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(overrides)
+            .map_err(|e| format!("Failed to serialize plugin overrides: {}", e))?;
+        fs::write(self.overrides_path(), json)
+            .map_err(|e| format!("Failed to write plugin overrides: {}", e))?;
+        Ok(())
     }
 
     /// Save plugin manifest to disk
@@ -184,11 +806,20 @@ impl PluginManager {
         // Load manifest from source
         let manifest = self.load_plugin_manifest(source_path)?;
         
+        // Refuse incompatible plugins up front.
+        Self::check_compatibility(&manifest)
+            .map_err(|msg| format!("Plugin '{}' {}", manifest.id, msg))?;
+
         // Check if plugin already exists
         if self.plugins.contains_key(&manifest.id) {
             return Err(format!("Plugin '{}' already installed", manifest.id));
         }
 
+        // preinstall hook runs against the source, before anything is copied.
+        if let Some(script) = manifest.scripts.get("preinstall") {
+            self.run_lifecycle_script("preinstall", source_path, script)?;
+        }
+
         // Copy plugin to plugins directory
         let target_path = self.plugins_dir.join(&manifest.id);
         self.copy_dir_all(source_path, &target_path)
@@ -197,27 +828,403 @@ impl PluginManager {
         // Add to plugins registry
         let plugin_info = PluginInfo {
             manifest: manifest.clone(),
-            path: target_path,
+            path: target_path.clone(),
             loaded: false,
             error: None,
+            checksum: None,
+            source: PluginSource::User,
         };
-        
+
         self.plugins.insert(manifest.id.clone(), plugin_info);
+
+        // postinstall hook runs against the installed copy; a failure rolls the
+        // whole install back so a half-configured plugin is never left behind.
+        if let Some(script) = manifest.scripts.get("postinstall") {
+            if let Err(e) = self.run_lifecycle_script("postinstall", &target_path, script) {
+                self.plugins.remove(&manifest.id);
+                let _ = fs::remove_dir_all(&target_path);
+                return Err(e);
+            }
+        }
+
         info!("Installed plugin: {} v{}", manifest.name, manifest.version);
-        
+
         Ok(manifest.id)
     }
 
-    /// Uninstall a plugin
-    pub fn uninstall_plugin(&mut self, id: &str) -> Result<(), String> {
-        if let Some(plugin) = self.plugins.remove(id) {
-            fs::remove_dir_all(&plugin.path)
-                .map_err(|e| format!("Failed to remove plugin directory: {}", e))?;
-            info!("Uninstalled plugin: {}", id);
-            Ok(())
+    /// Run a lifecycle hook script with the plugin directory as working dir.
+    ///
+    /// Both stdout and stderr are captured into the log. A non-zero exit is
+    /// surfaced as an error; callers treat that as fatal for `pre*` hooks.
+    fn run_lifecycle_script(&self, hook: &str, working_dir: &Path, script_rel: &str) -> Result<(), String> {
+        let script_path = working_dir.join(script_rel);
+        info!("Running {} hook: {:?}", hook, script_path);
+
+        let output = std::process::Command::new(&script_path)
+            .current_dir(working_dir)
+            .output()
+            .map_err(|e| format!("Failed to run {} hook '{}': {}", hook, script_rel, e))?;
+
+        if !output.stdout.is_empty() {
+            info!("[{}] {}", hook, String::from_utf8_lossy(&output.stdout).trim_end());
+        }
+        if !output.stderr.is_empty() {
+            warn!("[{}] {}", hook, String::from_utf8_lossy(&output.stderr).trim_end());
+        }
+
+        if !output.status.success() {
+            return Err(format!("{} hook exited with {}", hook, output.status));
+        }
+        Ok(())
+    }
+
+    /// Uninstall a plugin.
+    ///
+    /// Refuses to delete a plugin that other enabled plugins still depend on,
+    /// returning [`PluginError::InUseBy`] so dependents are not silently broken.
+    pub fn uninstall_plugin(&mut self, id: &str) -> Result<(), PluginError> {
+        if !self.plugins.contains_key(id) {
+            return Err(PluginError::NotFound(id.to_string()));
+        }
+
+        // Built-in plugins are read-only: they can only be disabled, not deleted.
+        if self.plugins.get(id).unwrap().source == PluginSource::BuiltIn {
+            return Err(PluginError::Other(format!(
+                "Plugin '{}' is built-in and cannot be uninstalled; disable it instead",
+                id
+            )));
+        }
+
+        let dependents = self.dependents_of(id);
+        if !dependents.is_empty() {
+            return Err(PluginError::InUseBy(id.to_string(), dependents));
+        }
+
+        let scripts = self.plugins.get(id).unwrap().manifest.scripts.clone();
+        let plugin_path = self.plugins.get(id).unwrap().path.clone();
+
+        // preuninstall hook runs while the plugin files are still in place; a
+        // non-zero exit aborts the uninstall.
+        if let Some(script) = scripts.get("preuninstall") {
+            self.run_lifecycle_script("preuninstall", &plugin_path, script)
+                .map_err(PluginError::Other)?;
+        }
+
+        let plugin = self.plugins.remove(id).unwrap();
+        self.instances.remove(id);
+
+        // Move the directory aside to a dot-prefixed staging path inside the
+        // plugins dir so the postuninstall hook can still run against its files
+        // once it is no longer "installed".
+        let staged = self
+            .plugins_dir
+            .join(format!(".{}.uninstall", id));
+        let _ = fs::remove_dir_all(&staged);
+        fs::rename(&plugin.path, &staged).map_err(|e| {
+            PluginError::Other(format!("Failed to remove plugin directory: {}", e))
+        })?;
+
+        if let Some(script) = scripts.get("postuninstall") {
+            // A failing postuninstall hook is logged but does not resurrect the plugin.
+            if let Err(e) = self.run_lifecycle_script("postuninstall", &staged, script) {
+                warn!("postuninstall hook for '{}' failed: {}", id, e);
+            }
+        }
+
+        fs::remove_dir_all(&staged).map_err(|e| {
+            PluginError::Other(format!("Failed to remove plugin directory: {}", e))
+        })?;
+        info!("Uninstalled plugin: {}", id);
+
+        // If this was a user override of a bundled plugin, re-expose the
+        // built-in copy so shipped functionality is not lost.
+        if let Some(builtin_dir) = self.builtin_dir.clone() {
+            let builtin_path = builtin_dir.join(id);
+            if builtin_path.is_dir() {
+                if let Ok(manifest) = self.load_plugin_manifest(&builtin_path) {
+                    let error = Self::check_compatibility(&manifest).err();
+                    self.plugins.insert(
+                        manifest.id.clone(),
+                        PluginInfo {
+                            manifest,
+                            path: builtin_path,
+                            loaded: false,
+                            error,
+                            checksum: None,
+                            source: PluginSource::BuiltIn,
+                        },
+                    );
+                    info!("Re-exposed built-in plugin: {}", id);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The writable user plugins directory this manager watches.
+    pub fn plugins_dir(&self) -> &Path {
+        &self.plugins_dir
+    }
+
+    /// Hot-reload a single plugin directory after a filesystem change.
+    ///
+    /// Re-parses and re-validates the manifest, updating or inserting the
+    /// corresponding [`PluginInfo`] (clearing or setting its `error`). A removed
+    /// directory drops the plugin; a plugin that was loaded is unloaded and
+    /// reloaded so edits take effect immediately. The returned [`PluginChange`]
+    /// lets the caller notify the UI.
+    pub fn reload_plugin_dir(&mut self, dir: &Path) -> PluginChange {
+        let dir_name = dir
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        // Directory gone: drop any plugin that lived there.
+        if !dir.exists() {
+            if let Some((id, _)) = self.plugins.iter().find(|(_, p)| p.path == dir) {
+                let id = id.clone();
+                self.plugins.remove(&id);
+                self.instances.remove(&id);
+                info!("Hot-reload: removed plugin '{}'", id);
+                return PluginChange::Removed(id);
+            }
+            return PluginChange::Removed(dir_name);
+        }
+
+        let manifest = match self.load_plugin_manifest(dir) {
+            Ok(manifest) => manifest,
+            Err(e) => {
+                warn!("Hot-reload: invalid plugin at {:?}: {}", dir, e);
+                // Record the error against an existing entry if we can find one.
+                if let Some((_, plugin)) = self.plugins.iter_mut().find(|(_, p)| p.path == dir) {
+                    plugin.error = Some(e);
+                    return PluginChange::Errored(plugin.manifest.id.clone());
+                }
+                return PluginChange::Errored(dir_name);
+            }
+        };
+
+        let previous = self.plugins.get(&manifest.id);
+        let existed = previous.is_some();
+        let was_loaded = previous.map(|p| p.loaded).unwrap_or(false);
+        // Preserve the prior entry's source/checksum so hot-reloading a
+        // repository-installed plugin doesn't drop its verified checksum;
+        // only a genuinely new plugin defaults to an unverified user install.
+        let (source, checksum) = previous
+            .map(|p| (p.source, p.checksum.clone()))
+            .unwrap_or((PluginSource::User, None));
+
+        let error = Self::check_compatibility(&manifest).err();
+        let id = manifest.id.clone();
+        self.plugins.insert(
+            id.clone(),
+            PluginInfo {
+                manifest,
+                path: dir.to_path_buf(),
+                loaded: false,
+                error,
+                checksum,
+                source,
+            },
+        );
+
+        // Re-run a previously loaded plugin so edits take effect live.
+        if was_loaded {
+            let _ = self.unload_plugin(&id);
+            if let Err(e) = self.load_plugin(&id) {
+                warn!("Hot-reload: failed to reload '{}': {}", id, e);
+            }
+        }
+
+        if existed {
+            info!("Hot-reload: updated plugin '{}'", id);
+            PluginChange::Updated(id)
         } else {
-            Err(format!("Plugin not found: {}", id))
+            info!("Hot-reload: added plugin '{}'", id);
+            PluginChange::Added(id)
+        }
+    }
+
+    /// Override the repository index URL (primarily for configuration/testing).
+    pub fn set_repository_url(&mut self, url: String) {
+        self.repository_url = url;
+    }
+
+    /// Configure the base64 ed25519 public key used to verify the signed index.
+    pub fn set_repository_public_key(&mut self, key: String) {
+        self.repository_public_key = Some(key);
+    }
+
+    /// Hand the manager a dictionary handle so loaded plugins can look up words.
+    ///
+    /// The handle is cloned into every plugin's host-function context the next
+    /// time it is loaded, so plugins loaded after this call can resolve
+    /// headwords through the core `lookup_word` ABI.
+    pub fn set_dictionary(&mut self, engine: SearchEngine) {
+        self.dictionary = Some(engine);
+    }
+
+    /// Fetch and verify the signed repository index.
+    pub fn fetch_index(&self) -> Result<RepositoryIndex, String> {
+        info!("Fetching plugin repository index from {}", self.repository_url);
+        let bytes = blocking_get_bytes(&self.repository_url)?;
+        let body = String::from_utf8(bytes)
+            .map_err(|e| format!("Repository index is not valid UTF-8: {}", e))?;
+
+        let index: RepositoryIndex = serde_json::from_str(&body)
+            .map_err(|e| format!("Failed to parse repository index: {}", e))?;
+
+        verify_index_signature(&index, self.repository_public_key.as_deref())?;
+        Ok(index)
+    }
+
+    /// Install a plugin at a specific version from the remote repository.
+    ///
+    /// The archive is downloaded, its SHA-256 verified against the index entry,
+    /// and only then extracted into the plugins directory. The verified checksum
+    /// is stored on the resulting [`PluginInfo`].
+    pub fn install_plugin_from_repository(&mut self, id: &str, version: &str) -> Result<String, String> {
+        if self.plugins.contains_key(id) {
+            return Err(format!("Plugin '{}' already installed", id));
+        }
+
+        let index = self.fetch_index()?;
+        let entry = find_version(&index, id, version)
+            .ok_or_else(|| format!("Plugin '{}' v{} not found in repository", id, version))?;
+
+        let target_path = self.plugins_dir.join(id);
+        let checksum = self.download_verify_extract(entry, &target_path)?;
+
+        let manifest = self.load_plugin_manifest(&target_path)?;
+        if let Err(msg) = Self::check_compatibility(&manifest) {
+            // Roll back the extracted files on an incompatible install.
+            let _ = fs::remove_dir_all(&target_path);
+            return Err(format!("Plugin '{}' {}", id, msg));
+        }
+
+        let plugin_info = PluginInfo {
+            manifest: manifest.clone(),
+            path: target_path,
+            loaded: false,
+            error: None,
+            checksum: Some(checksum),
+            source: PluginSource::User,
+        };
+        self.plugins.insert(manifest.id.clone(), plugin_info);
+        info!("Installed plugin from repository: {} v{}", manifest.name, manifest.version);
+        Ok(manifest.id)
+    }
+
+    /// Compare each installed plugin against the newest compatible repository
+    /// version, returning the set of available upgrades.
+    pub fn check_for_updates(&self) -> Result<Vec<AvailableUpdate>, String> {
+        let index = self.fetch_index()?;
+        let mut updates = Vec::new();
+
+        for plugin in self.plugins.values() {
+            let current = match Version::parse(&plugin.manifest.version) {
+                Ok(v) => v,
+                Err(_) => continue,
+            };
+
+            if let Some(latest) = self.newest_compatible(&index, &plugin.manifest.id) {
+                if let Ok(latest_ver) = Version::parse(&latest.version) {
+                    if latest_ver > current {
+                        updates.push(AvailableUpdate {
+                            id: plugin.manifest.id.clone(),
+                            current_version: plugin.manifest.version.clone(),
+                            latest_version: latest.version.clone(),
+                        });
+                    }
+                }
+            }
         }
+
+        Ok(updates)
+    }
+
+    /// Update an installed plugin to the newest compatible repository version.
+    ///
+    /// The new version is downloaded and verified into a temporary directory
+    /// first, then swapped in with a rename, so a failed download can never
+    /// corrupt the working installation.
+    pub fn update_plugin(&mut self, id: &str) -> Result<(), String> {
+        let index = self.fetch_index()?;
+        let latest = self
+            .newest_compatible(&index, id)
+            .ok_or_else(|| format!("No compatible version available for '{}'", id))?
+            .clone();
+
+        let target_path = self
+            .plugins
+            .get(id)
+            .ok_or_else(|| format!("Plugin not found: {}", id))?
+            .path
+            .clone();
+
+        // Stage into a sibling temp directory, verify, then atomically swap.
+        let temp_path = self.plugins_dir.join(format!(".{}.update", id));
+        let _ = fs::remove_dir_all(&temp_path);
+        let checksum = self.download_verify_extract(&latest, &temp_path)?;
+
+        // Move the current install aside first, swap the verified copy into
+        // place, and only delete the old copy once the swap has succeeded, so
+        // a failed rename can never leave the plugin without a directory.
+        let backup_path = self.plugins_dir.join(format!(".{}.old", id));
+        let _ = fs::remove_dir_all(&backup_path);
+        fs::rename(&target_path, &backup_path)
+            .map_err(|e| format!("Failed to move aside old plugin directory: {}", e))?;
+        if let Err(e) = fs::rename(&temp_path, &target_path) {
+            // Restore the previous install before surfacing the failure.
+            let _ = fs::rename(&backup_path, &target_path);
+            let _ = fs::remove_dir_all(&temp_path);
+            return Err(format!("Failed to swap in updated plugin: {}", e));
+        }
+        let _ = fs::remove_dir_all(&backup_path);
+
+        let manifest = self.load_plugin_manifest(&target_path)?;
+        self.instances.remove(id);
+        if let Some(plugin) = self.plugins.get_mut(id) {
+            plugin.manifest = manifest.clone();
+            plugin.loaded = false;
+            plugin.error = None;
+            plugin.checksum = Some(checksum);
+        }
+        info!("Updated plugin '{}' to v{}", id, manifest.version);
+        Ok(())
+    }
+
+    /// Newest repository version of `id` that is compatible with this engine.
+    fn newest_compatible<'a>(&self, index: &'a RepositoryIndex, id: &str) -> Option<&'a RepositoryVersion> {
+        let engine = Version::parse(ENGINE_VERSION).ok()?;
+        index
+            .plugins
+            .iter()
+            .find(|p| p.id == id)?
+            .versions
+            .iter()
+            .filter(|v| version_in_engine_bounds(v, &engine))
+            .filter_map(|v| Version::parse(&v.version).ok().map(|parsed| (parsed, v)))
+            .max_by(|a, b| a.0.cmp(&b.0))
+            .map(|(_, v)| v)
+    }
+
+    /// Download an archive, verify its checksum, and extract it into `dest`.
+    /// Returns the verified SHA-256 on success.
+    fn download_verify_extract(&self, entry: &RepositoryVersion, dest: &Path) -> Result<String, String> {
+        let bytes = blocking_get_bytes(&entry.url)?;
+
+        let digest = sha256_hex(&bytes);
+        if digest != entry.sha256.to_lowercase() {
+            return Err(format!(
+                "Checksum mismatch: expected {}, got {}",
+                entry.sha256, digest
+            ));
+        }
+
+        extract_archive(&bytes, dest)?;
+        Ok(digest)
     }
 
     /// Recursively copy directory
@@ -244,12 +1251,16 @@ impl PluginManager {
         let enabled = self.plugins.values().filter(|p| p.manifest.enabled).count();
         let loaded = self.plugins.values().filter(|p| p.loaded).count();
         let errors = self.plugins.values().filter(|p| p.error.is_some()).count();
+        let builtin = self.plugins.values().filter(|p| p.source == PluginSource::BuiltIn).count();
+        let user = self.plugins.values().filter(|p| p.source == PluginSource::User).count();
 
         PluginManagerStats {
             total,
             enabled,
             loaded,
             errors,
+            builtin,
+            user,
         }
     }
 }
@@ -260,4 +1271,234 @@ pub struct PluginManagerStats {
     pub enabled: usize,
     pub loaded: usize,
     pub errors: usize,
+    pub builtin: usize,
+    pub user: usize,
+}
+
+// --- Remote repository helpers ---
+
+/// Perform a blocking HTTP GET off the async runtime and return the body bytes.
+///
+/// The repository methods are synchronous but are invoked from async Tauri
+/// commands. Calling `reqwest::blocking` directly on a Tokio worker thread
+/// panics with "Cannot start a runtime from within a runtime", so the request
+/// is run on a dedicated std thread that has no ambient runtime.
+fn blocking_get_bytes(url: &str) -> Result<Vec<u8>, String> {
+    let url = url.to_string();
+    std::thread::spawn(move || {
+        let response = reqwest::blocking::get(&url)
+            .map_err(|e| format!("Failed to fetch {}: {}", url, e))?;
+        response
+            .bytes()
+            .map(|b| b.to_vec())
+            .map_err(|e| format!("Failed to read response from {}: {}", url, e))
+    })
+    .join()
+    .map_err(|_| "HTTP worker thread panicked".to_string())?
+}
+
+/// Locate a specific plugin version within the repository index.
+fn find_version<'a>(index: &'a RepositoryIndex, id: &str, version: &str) -> Option<&'a RepositoryVersion> {
+    index
+        .plugins
+        .iter()
+        .find(|p| p.id == id)?
+        .versions
+        .iter()
+        .find(|v| v.version == version)
+}
+
+/// Whether a repository version's declared engine bounds include `engine`.
+fn version_in_engine_bounds(entry: &RepositoryVersion, engine: &Version) -> bool {
+    if let Some(min) = &entry.min_engine_version {
+        match Version::parse(min) {
+            Ok(min) if *engine < min => return false,
+            Err(_) => return false,
+            _ => {}
+        }
+    }
+    if let Some(max) = &entry.max_engine_version {
+        match Version::parse(max) {
+            Ok(max) if *engine >= max => return false,
+            Err(_) => return false,
+            _ => {}
+        }
+    }
+    true
+}
+
+/// Lowercase hex SHA-256 of a byte slice.
+fn sha256_hex(bytes: &[u8]) -> String {
+    let digest = Sha256::digest(bytes);
+    let mut out = String::with_capacity(digest.len() * 2);
+    for byte in digest {
+        out.push_str(&format!("{:02x}", byte));
+    }
+    out
+}
+
+/// Extract a zip plugin archive into `dest`.
+fn extract_archive(bytes: &[u8], dest: &Path) -> Result<(), String> {
+    let reader = std::io::Cursor::new(bytes);
+    let mut archive = zip::ZipArchive::new(reader)
+        .map_err(|e| format!("Failed to open plugin archive: {}", e))?;
+    archive
+        .extract(dest)
+        .map_err(|e| format!("Failed to extract plugin archive: {}", e))?;
+    Ok(())
+}
+
+/// Verify the ed25519 signature over the repository index's plugin listing.
+///
+/// An index without a signature is rejected, as are indices signed by a key
+/// other than the configured repository key. When no signing key has been
+/// configured the check fails loudly rather than validating against a
+/// placeholder, so a misconfigured build cannot silently trust any index.
+fn verify_index_signature(index: &RepositoryIndex, public_key: Option<&str>) -> Result<(), String> {
+    use base64::{engine::general_purpose::STANDARD, Engine as _};
+    use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+
+    let public_key = public_key.ok_or_else(|| {
+        "Repository signing key is not configured; cannot verify index".to_string()
+    })?;
+
+    let signature_b64 = index
+        .signature
+        .as_ref()
+        .ok_or_else(|| "Repository index is not signed".to_string())?;
+
+    let key_bytes = STANDARD
+        .decode(public_key)
+        .map_err(|e| format!("Invalid repository public key: {}", e))?;
+    let key_array: [u8; 32] = key_bytes
+        .as_slice()
+        .try_into()
+        .map_err(|_| "Repository public key must be 32 bytes".to_string())?;
+    let verifying_key = VerifyingKey::from_bytes(&key_array)
+        .map_err(|e| format!("Invalid repository public key: {}", e))?;
+
+    let signature_bytes = STANDARD
+        .decode(signature_b64)
+        .map_err(|e| format!("Invalid index signature: {}", e))?;
+    let signature = Signature::from_slice(&signature_bytes)
+        .map_err(|e| format!("Invalid index signature: {}", e))?;
+
+    // The signature covers the canonical JSON of the plugin listing.
+    let message = serde_json::to_vec(&index.plugins)
+        .map_err(|e| format!("Failed to canonicalize index: {}", e))?;
+
+    verifying_key
+        .verify(&message, &signature)
+        .map_err(|_| "Repository index signature verification failed".to_string())
+}
+
+/// Base64 ed25519 public key the repository index is expected to be signed
+/// with. Left unset here: a real key must be provisioned (at build time or via
+/// [`PluginManager::set_repository_public_key`]) before signature verification
+/// can succeed, so an unconfigured build rejects every index instead of
+/// trusting a placeholder.
+const REPOSITORY_PUBLIC_KEY: Option<&str> = None;
+
+// --- Capability-gated host functions exposed to sandboxed plugins ---
+
+/// Read a UTF-8 string argument out of plugin memory.
+fn read_input_string(plugin: &mut CurrentPlugin, val: &Val) -> Result<String, extism::Error> {
+    let handle = plugin
+        .memory_from_val(val)
+        .ok_or_else(|| extism::Error::msg("host function argument is not a memory handle"))?;
+    let bytes = plugin.memory_bytes(handle)?;
+    Ok(String::from_utf8_lossy(bytes).into_owned())
+}
+
+/// Write bytes back into plugin memory and point `out` at them.
+fn write_output_bytes(
+    plugin: &mut CurrentPlugin,
+    out: &mut Val,
+    data: &[u8],
+) -> Result<(), extism::Error> {
+    let handle = plugin.memory_new(data)?;
+    *out = plugin.memory_to_val(handle);
+    Ok(())
+}
+
+/// Core dictionary ABI: look up a headword and return its formatted definition.
+fn host_lookup_word(
+    plugin: &mut CurrentPlugin,
+    inputs: &[Val],
+    outputs: &mut [Val],
+    user: UserData<HostContext>,
+) -> Result<(), extism::Error> {
+    let word = read_input_string(plugin, &inputs[0])?;
+
+    let context = user.get()?;
+    let context = context.lock().unwrap();
+    let engine = context
+        .dictionary
+        .as_ref()
+        .ok_or_else(|| extism::Error::msg("dictionary is not available"))?;
+
+    let result = engine
+        .search(&word)
+        .map_err(|e| extism::Error::msg(format!("lookup failed: {}", e)))?;
+    let formatted = serde_json::to_string(&result.entries)
+        .map_err(|e| extism::Error::msg(format!("failed to serialize definition: {}", e)))?;
+
+    write_output_bytes(plugin, &mut outputs[0], formatted.as_bytes())
+}
+
+/// `network` capability: perform an HTTP GET and return the response body.
+fn host_http_get(
+    plugin: &mut CurrentPlugin,
+    inputs: &[Val],
+    outputs: &mut [Val],
+    _user: UserData<HostContext>,
+) -> Result<(), extism::Error> {
+    let url = read_input_string(plugin, &inputs[0])?;
+    let bytes = blocking_get_bytes(&url).map_err(extism::Error::msg)?;
+    write_output_bytes(plugin, &mut outputs[0], &bytes)
+}
+
+/// `fs-read` capability: read a file relative to the plugin's own directory.
+fn host_fs_read(
+    plugin: &mut CurrentPlugin,
+    inputs: &[Val],
+    outputs: &mut [Val],
+    user: UserData<HostContext>,
+) -> Result<(), extism::Error> {
+    let rel = read_input_string(plugin, &inputs[0])?;
+
+    let context = user.get()?;
+    let context = context.lock().unwrap();
+
+    // Resolve the request inside the plugin directory and reject any path that
+    // escapes it (e.g. via `..`), so a plugin cannot read arbitrary files.
+    let requested = context.plugin_dir.join(&rel);
+    let canonical = requested
+        .canonicalize()
+        .map_err(|e| extism::Error::msg(format!("failed to read '{}': {}", rel, e)))?;
+    if !canonical.starts_with(&context.plugin_dir) {
+        return Err(extism::Error::msg(format!(
+            "path '{}' escapes the plugin directory",
+            rel
+        )));
+    }
+
+    let data = fs::read(&canonical)
+        .map_err(|e| extism::Error::msg(format!("failed to read '{}': {}", rel, e)))?;
+    write_output_bytes(plugin, &mut outputs[0], &data)
+}
+
+/// `clipboard` capability: write text to the system clipboard.
+fn host_clipboard_write(
+    plugin: &mut CurrentPlugin,
+    inputs: &[Val],
+    _outputs: &mut [Val],
+    _user: UserData<HostContext>,
+) -> Result<(), extism::Error> {
+    let text = read_input_string(plugin, &inputs[0])?;
+    let mut clipboard = arboard::Clipboard::new()
+        .map_err(|e| extism::Error::msg(format!("failed to open clipboard: {}", e)))?;
+    clipboard
+        .set_text(text)
+        .map_err(|e| extism::Error::msg(format!("failed to write clipboard: {}", e)))
 }
\ No newline at end of file
@@ -0,0 +1,90 @@
+use std::path::{Path, PathBuf};
+use log::{info, warn};
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use tauri::{Emitter, Manager};
+
+use crate::AppState;
+
+/// Event name emitted to the frontend whenever a watched plugin changes.
+pub const PLUGIN_CHANGED_EVENT: &str = "plugin-changed";
+
+/// Start watching the user plugins directory for live changes.
+///
+/// This is opt-in (intended for plugin development): on any change under the
+/// plugins directory the affected plugin is re-parsed, re-validated and, if it
+/// was loaded, reloaded, and a [`PLUGIN_CHANGED_EVENT`] is emitted so the UI
+/// can reflect added/removed/errored plugins without a restart. The returned
+/// watcher must be kept alive for as long as watching should continue.
+pub fn start(app: tauri::AppHandle) -> Result<RecommendedWatcher, String> {
+    let plugins_dir = {
+        let state = app.state::<AppState>();
+        let guard = state.plugin_manager.lock().unwrap();
+        match guard.as_ref() {
+            Some(manager) => manager.plugins_dir().to_path_buf(),
+            None => return Err("Plugin manager not initialized".to_string()),
+        }
+    };
+
+    let handler_app = app.clone();
+    let watch_root = plugins_dir.clone();
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+        match res {
+            Ok(event) => handle_event(&handler_app, &watch_root, &event.paths),
+            Err(e) => warn!("Plugin watcher error: {}", e),
+        }
+    })
+    .map_err(|e| format!("Failed to create plugin watcher: {}", e))?;
+
+    watcher
+        .watch(&plugins_dir, RecursiveMode::Recursive)
+        .map_err(|e| format!("Failed to watch plugins directory: {}", e))?;
+
+    info!("Watching plugins directory for changes: {:?}", plugins_dir);
+    Ok(watcher)
+}
+
+/// Reload each affected top-level plugin directory and notify the UI.
+fn handle_event(app: &tauri::AppHandle, watch_root: &Path, paths: &[PathBuf]) {
+    // Map every changed path back to its immediate plugin directory and dedupe.
+    let mut dirs: Vec<PathBuf> = Vec::new();
+    for path in paths {
+        if let Some(dir) = plugin_dir_for(watch_root, path) {
+            if !dirs.contains(&dir) {
+                dirs.push(dir);
+            }
+        }
+    }
+
+    for dir in dirs {
+        let state = app.state::<AppState>();
+        let change = {
+            let mut guard = state.plugin_manager.lock().unwrap();
+            match guard.as_mut() {
+                Some(manager) => Some(manager.reload_plugin_dir(&dir)),
+                None => None,
+            }
+        };
+
+        if let Some(change) = change {
+            if let Err(e) = app.emit(PLUGIN_CHANGED_EVENT, change) {
+                warn!("Failed to emit {} event: {}", PLUGIN_CHANGED_EVENT, e);
+            }
+        }
+    }
+}
+
+/// Resolve the immediate child of `watch_root` that contains `path`.
+///
+/// Returns `None` for entries whose name starts with `.`, since those are the
+/// manager's own dotfiles and staging artifacts (`.plugin-overrides.json`,
+/// `.{id}.update`, `.{id}.old`, `.{id}.uninstall`) rather than plugin
+/// directories, and reloading them as one would emit a spurious
+/// `plugin-changed` event and race install/update operations writing them.
+fn plugin_dir_for(watch_root: &Path, path: &Path) -> Option<PathBuf> {
+    let relative = path.strip_prefix(watch_root).ok()?;
+    let first = relative.components().next()?;
+    if first.as_os_str().to_str()?.starts_with('.') {
+        return None;
+    }
+    Some(watch_root.join(first.as_os_str()))
+}
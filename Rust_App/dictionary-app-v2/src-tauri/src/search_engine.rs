@@ -1,12 +1,32 @@
 use crate::database::{Database, DictionaryEntry};
 use serde::{Deserialize, Serialize};
 use log::{info, debug, error, warn};
+use regex::RegexBuilder;
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::fs;
 use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
+/// How a `term` should be matched against the dictionary headwords.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchMode {
+    /// Inflection-aware exact lookup (the historical behavior).
+    #[default]
+    Exact,
+    /// Match headwords starting with `term`.
+    Prefix,
+    /// Rank headwords by edit distance to `term`.
+    Fuzzy,
+    /// Match headwords against `term` compiled as a regular expression.
+    Regex,
+}
+
+/// Maximum number of headwords resolved by the prefix/fuzzy/regex paths; each
+/// headword may contribute several entries to the result.
+const MODE_RESULT_LIMIT: usize = 25;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchResult {
     pub entries: Vec<DictionaryEntry>,
@@ -161,6 +181,104 @@ impl SearchEngine {
         })
     }
     
+    /// Search the dictionary using the requested matching strategy.
+    ///
+    /// `Exact` delegates to the inflection-aware [`search`](Self::search) path;
+    /// the other modes resolve a set of candidate lemmas and fetch their entries.
+    pub fn search_with_mode(&self, term: &str, mode: SearchMode) -> Result<SearchResult, Box<dyn std::error::Error>> {
+        match mode {
+            SearchMode::Exact => self.search(term),
+            SearchMode::Prefix => self.search_prefix(term),
+            SearchMode::Fuzzy => self.search_fuzzy(term),
+            SearchMode::Regex => self.search_regex(term),
+        }
+    }
+
+    fn search_prefix(&self, term: &str) -> Result<SearchResult, Box<dyn std::error::Error>> {
+        let normalized_term = term.trim().to_lowercase();
+        let lemmas = self.database.search_by_prefix(&normalized_term, MODE_RESULT_LIMIT)?;
+        self.collect_entries(&normalized_term, lemmas)
+    }
+
+    fn search_fuzzy(&self, term: &str) -> Result<SearchResult, Box<dyn std::error::Error>> {
+        let normalized_term = term.trim().to_lowercase();
+        let candidates = self.database.get_all_lemmas()?;
+
+        // Rank every headword by edit distance, keeping the closest matches.
+        let mut ranked: Vec<(usize, String)> = candidates
+            .into_iter()
+            .map(|lemma| (levenshtein(&normalized_term, &lemma.to_lowercase()), lemma))
+            .collect();
+        ranked.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.cmp(&b.1)));
+
+        let lemmas: Vec<String> = ranked
+            .into_iter()
+            .take(MODE_RESULT_LIMIT)
+            .map(|(_, lemma)| lemma)
+            .collect();
+
+        self.collect_entries(&normalized_term, lemmas)
+    }
+
+    fn search_regex(&self, term: &str) -> Result<SearchResult, Box<dyn std::error::Error>> {
+        // Match case-insensitively instead of lowercasing the pattern, which would
+        // mangle metacharacters like `\D`/`\W`/`\S`/`\B` and `[A-Z]` classes.
+        let normalized_term = term.trim();
+        let regex = RegexBuilder::new(normalized_term)
+            .case_insensitive(true)
+            .build()?;
+        let candidates = self.database.get_all_lemmas()?;
+
+        let lemmas: Vec<String> = candidates
+            .into_iter()
+            .filter(|lemma| regex.is_match(lemma))
+            .take(MODE_RESULT_LIMIT)
+            .collect();
+
+        self.collect_entries(normalized_term, lemmas)
+    }
+
+    /// Fetch and de-duplicate entries for a resolved list of lemmas.
+    fn collect_entries(&self, query: &str, lemmas: Vec<String>) -> Result<SearchResult, Box<dyn std::error::Error>> {
+        let search_start = Instant::now();
+
+        let mut stats = self.stats.lock().unwrap();
+        stats.total_searches += 1;
+        drop(stats);
+
+        let mut all_entries = Vec::new();
+        let mut searched_terms = Vec::new();
+
+        for lemma in &lemmas {
+            match self.database.search_by_lemma(lemma) {
+                Ok(mut entries) => {
+                    if !entries.is_empty() {
+                        searched_terms.push(lemma.clone());
+                        all_entries.append(&mut entries);
+                    }
+                }
+                Err(e) => error!("Database search error for '{}': {}", lemma, e),
+            }
+        }
+
+        all_entries.sort_by(|a, b| a.id.cmp(&b.id));
+        all_entries.dedup_by(|a, b| a.id == b.id);
+
+        let query_used = if searched_terms.is_empty() {
+            query.to_string()
+        } else {
+            searched_terms.join(", ")
+        };
+
+        Ok(SearchResult {
+            total_results: all_entries.len(),
+            entries: all_entries,
+            search_time_ms: search_start.elapsed().as_millis() as u64,
+            query_used,
+            found_inflections: false,
+        })
+    }
+
     fn get_search_lemmas(&self, term: &str) -> (Vec<String>, bool) {
         let inflections = self.inflection_map.lock().unwrap();
         
@@ -241,6 +359,35 @@ impl SearchEngine {
     }
 }
 
+/// Classic dynamic-programming Levenshtein edit distance between two strings.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    if a.is_empty() {
+        return b.len();
+    }
+    if b.is_empty() {
+        return a.len();
+    }
+
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr: Vec<usize> = vec![0; b.len() + 1];
+
+    for (i, ca) in a.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == cb { 0 } else { 1 };
+            curr[j + 1] = (prev[j + 1] + 1)
+                .min(curr[j] + 1)
+                .min(prev[j] + cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b.len()]
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SearchEngineStats {
     pub total_searches: u64,
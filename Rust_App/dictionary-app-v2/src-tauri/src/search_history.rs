@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs;
+use std::path::PathBuf;
+use tauri::Manager;
+
+/// Maximum number of distinct queries kept in the recent-search ring buffer.
+pub const HISTORY_CAPACITY: usize = 50;
+
+/// File (relative to the app data dir) the query history is persisted to.
+const HISTORY_FILENAME: &str = "search-history.json";
+
+/// On-disk representation of the recent-query history.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+struct StoredHistory {
+    queries: Vec<String>,
+}
+
+/// Push `query` onto the front of the ring buffer, keeping it distinct and bounded.
+///
+/// An existing occurrence of the same query is moved to the front rather than
+/// duplicated, and the oldest entries are dropped once [`HISTORY_CAPACITY`] is
+/// exceeded.
+pub fn record(history: &mut VecDeque<String>, query: &str) {
+    let query = query.trim();
+    if query.is_empty() {
+        return;
+    }
+
+    if let Some(pos) = history.iter().position(|q| q == query) {
+        history.remove(pos);
+    }
+
+    history.push_front(query.to_string());
+
+    while history.len() > HISTORY_CAPACITY {
+        history.pop_back();
+    }
+}
+
+/// Resolve the path of the history file inside the app data directory.
+fn history_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    let data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to resolve app data dir: {}", e))?;
+
+    if !data_dir.exists() {
+        fs::create_dir_all(&data_dir)
+            .map_err(|e| format!("Failed to create app data dir: {}", e))?;
+    }
+
+    Ok(data_dir.join(HISTORY_FILENAME))
+}
+
+/// Load the persisted history, newest query first. Empty when no file exists.
+pub fn load(app: &tauri::AppHandle) -> Result<VecDeque<String>, String> {
+    let path = history_path(app)?;
+    if !path.exists() {
+        return Ok(VecDeque::new());
+    }
+
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read search history: {}", e))?;
+    let stored: StoredHistory = serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse search history: {}", e))?;
+
+    Ok(stored.queries.into_iter().collect())
+}
+
+/// Persist the current history to disk.
+pub fn save(app: &tauri::AppHandle, history: &VecDeque<String>) -> Result<(), String> {
+    let stored = StoredHistory {
+        queries: history.iter().cloned().collect(),
+    };
+    let path = history_path(app)?;
+    let json = serde_json::to_string_pretty(&stored)
+        .map_err(|e| format!("Failed to serialize search history: {}", e))?;
+    fs::write(&path, json)
+        .map_err(|e| format!("Failed to write search history: {}", e))
+}
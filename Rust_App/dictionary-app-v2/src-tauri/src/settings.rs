@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use tauri::Manager;
+
+/// File (relative to the app data dir) the persisted settings are written to.
+const SETTINGS_FILENAME: &str = "settings.json";
+
+/// Persisted application-level preferences that outlive a single session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppSettings {
+    /// Whether the popup should stay visible across every virtual desktop /
+    /// workspace instead of being bound to the one it was opened on.
+    #[serde(default)]
+    pub visible_on_all_workspaces: bool,
+
+    /// Pixel gap between the cursor and the window when it is summoned near
+    /// where the user is reading.
+    #[serde(default = "default_cursor_offset")]
+    pub cursor_offset: i32,
+}
+
+fn default_cursor_offset() -> i32 {
+    50
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        AppSettings {
+            visible_on_all_workspaces: false,
+            cursor_offset: default_cursor_offset(),
+        }
+    }
+}
+
+/// Resolve the path of the settings file inside the app data directory.
+fn settings_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    let data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to resolve app data dir: {}", e))?;
+
+    if !data_dir.exists() {
+        fs::create_dir_all(&data_dir)
+            .map_err(|e| format!("Failed to create app data dir: {}", e))?;
+    }
+
+    Ok(data_dir.join(SETTINGS_FILENAME))
+}
+
+/// Load persisted settings, falling back to defaults when no file exists.
+pub fn load(app: &tauri::AppHandle) -> Result<AppSettings, String> {
+    let path = settings_path(app)?;
+    if !path.exists() {
+        return Ok(AppSettings::default());
+    }
+
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read settings: {}", e))?;
+    serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse settings: {}", e))
+}
+
+/// Persist the given settings to disk.
+pub fn save(app: &tauri::AppHandle, settings: &AppSettings) -> Result<(), String> {
+    let path = settings_path(app)?;
+    let json = serde_json::to_string_pretty(settings)
+        .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+    fs::write(&path, json)
+        .map_err(|e| format!("Failed to write settings: {}", e))
+}
@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use tauri::Manager;
+
+/// File (relative to the app data dir) the shortcut configuration is stored in.
+const SHORTCUTS_FILENAME: &str = "shortcuts.json";
+
+/// Logical actions that can be bound to a global accelerator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShortcutAction {
+    ToggleWindow,
+    QuitApp,
+}
+
+/// Persisted mapping from logical actions to accelerator strings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShortcutConfig {
+    pub bindings: HashMap<ShortcutAction, String>,
+}
+
+impl Default for ShortcutConfig {
+    fn default() -> Self {
+        // The historical hardcoded bindings, now used only as seed defaults.
+        let mut bindings = HashMap::new();
+        bindings.insert(ShortcutAction::ToggleWindow, "Ctrl+Alt+D".to_string());
+        bindings.insert(ShortcutAction::QuitApp, "Ctrl+Shift+Q".to_string());
+        ShortcutConfig { bindings }
+    }
+}
+
+/// Resolve the path of the shortcut config inside the app data directory.
+fn config_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    let data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to resolve app data dir: {}", e))?;
+
+    if !data_dir.exists() {
+        fs::create_dir_all(&data_dir)
+            .map_err(|e| format!("Failed to create app data dir: {}", e))?;
+    }
+
+    Ok(data_dir.join(SHORTCUTS_FILENAME))
+}
+
+/// Load the shortcut configuration, falling back to defaults when absent.
+pub fn load(app: &tauri::AppHandle) -> Result<ShortcutConfig, String> {
+    let path = config_path(app)?;
+    if !path.exists() {
+        return Ok(ShortcutConfig::default());
+    }
+
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read shortcut config: {}", e))?;
+    serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse shortcut config: {}", e))
+}
+
+/// Persist the shortcut configuration to disk.
+pub fn save(app: &tauri::AppHandle, config: &ShortcutConfig) -> Result<(), String> {
+    let path = config_path(app)?;
+    let json = serde_json::to_string_pretty(config)
+        .map_err(|e| format!("Failed to serialize shortcut config: {}", e))?;
+    fs::write(&path, json)
+        .map_err(|e| format!("Failed to write shortcut config: {}", e))
+}
@@ -0,0 +1,186 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use log::{info, warn};
+use tauri::{Manager, PhysicalPosition, PhysicalSize};
+
+/// File (relative to the app data dir) the serialized window state is written to.
+const STATE_FILENAME: &str = "window-state.json";
+
+/// Bitflags describing which window properties should be persisted and restored.
+///
+/// Callers can opt into saving only a subset (e.g. position without size) by
+/// combining the constants with `|`, mirroring `tauri-plugin-window-state`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StateFlags(u32);
+
+impl StateFlags {
+    pub const POSITION: StateFlags = StateFlags(1 << 0);
+    pub const SIZE: StateFlags = StateFlags(1 << 1);
+    pub const MAXIMIZED: StateFlags = StateFlags(1 << 2);
+    pub const VISIBLE: StateFlags = StateFlags(1 << 3);
+    /// Every tracked property.
+    pub const ALL: StateFlags = StateFlags(0b1111);
+
+    /// Build flags from raw bits, dropping any unknown bits.
+    pub fn from_bits_truncate(bits: u32) -> Self {
+        StateFlags(bits & Self::ALL.0)
+    }
+
+    /// Raw bit representation, for round-tripping through the frontend.
+    pub fn bits(self) -> u32 {
+        self.0
+    }
+
+    /// Whether every bit in `other` is set on `self`.
+    pub fn contains(self, other: StateFlags) -> bool {
+        (self.0 & other.0) == other.0
+    }
+}
+
+impl std::ops::BitOr for StateFlags {
+    type Output = StateFlags;
+
+    fn bitor(self, rhs: StateFlags) -> StateFlags {
+        StateFlags(self.0 | rhs.0)
+    }
+}
+
+/// Serialized geometry of the main window, persisted between sessions.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct WindowState {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub maximized: bool,
+    pub visible: bool,
+}
+
+/// Resolve the path of the window-state file inside the app data directory.
+fn state_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    let data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to resolve app data dir: {}", e))?;
+
+    if !data_dir.exists() {
+        fs::create_dir_all(&data_dir)
+            .map_err(|e| format!("Failed to create app data dir: {}", e))?;
+    }
+
+    Ok(data_dir.join(STATE_FILENAME))
+}
+
+/// Read the persisted window state, returning `None` when no file exists yet.
+fn load_state(app: &tauri::AppHandle) -> Result<Option<WindowState>, String> {
+    let path = state_path(app)?;
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read window state: {}", e))?;
+    let state = serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse window state: {}", e))?;
+
+    Ok(Some(state))
+}
+
+/// Persist the selected properties of the main window to disk.
+///
+/// Existing values for properties not covered by `flags` are preserved, so
+/// callers can update position and size independently.
+pub fn save_window_state(app: &tauri::AppHandle, flags: StateFlags) -> Result<(), String> {
+    let window = app
+        .get_webview_window("main")
+        .ok_or_else(|| "Main window not found".to_string())?;
+
+    let mut state = load_state(app)?.unwrap_or_default();
+
+    if flags.contains(StateFlags::POSITION) {
+        if let Ok(position) = window.outer_position() {
+            state.x = position.x;
+            state.y = position.y;
+        }
+    }
+
+    if flags.contains(StateFlags::SIZE) {
+        if let Ok(size) = window.inner_size() {
+            state.width = size.width;
+            state.height = size.height;
+        }
+    }
+
+    if flags.contains(StateFlags::MAXIMIZED) {
+        state.maximized = window.is_maximized().unwrap_or(false);
+    }
+
+    if flags.contains(StateFlags::VISIBLE) {
+        state.visible = window.is_visible().unwrap_or(true);
+    }
+
+    let path = state_path(app)?;
+    let json = serde_json::to_string_pretty(&state)
+        .map_err(|e| format!("Failed to serialize window state: {}", e))?;
+    fs::write(&path, json)
+        .map_err(|e| format!("Failed to write window state: {}", e))?;
+
+    Ok(())
+}
+
+/// Whether a window state file has ever been persisted to disk.
+///
+/// Any saved state counts, whatever flags it was saved with; whether that
+/// save included `POSITION` is not checked.
+///
+/// Show paths that would otherwise anchor the popup to the cursor consult
+/// this first, so a position the user dragged the window to (and that was
+/// saved on move/resize/close) isn't clobbered the next time the window is
+/// shown.
+pub fn has_saved_position(app: &tauri::AppHandle) -> bool {
+    matches!(load_state(app), Ok(Some(_)))
+}
+
+/// Restore the selected properties of the main window from disk.
+///
+/// Returns `Ok(false)` when no saved state exists, allowing the caller to fall
+/// back to the cursor-anchored placement.
+pub fn restore_window_state(app: &tauri::AppHandle, flags: StateFlags) -> Result<bool, String> {
+    let state = match load_state(app)? {
+        Some(state) => state,
+        None => {
+            info!("No saved window state found");
+            return Ok(false);
+        }
+    };
+
+    let window = app
+        .get_webview_window("main")
+        .ok_or_else(|| "Main window not found".to_string())?;
+
+    if flags.contains(StateFlags::SIZE) && state.width > 0 && state.height > 0 {
+        if let Err(e) = window.set_size(PhysicalSize::new(state.width, state.height)) {
+            warn!("Failed to restore window size: {}", e);
+        }
+    }
+
+    if flags.contains(StateFlags::POSITION) {
+        if let Err(e) = window.set_position(PhysicalPosition::new(state.x, state.y)) {
+            warn!("Failed to restore window position: {}", e);
+        }
+    }
+
+    if flags.contains(StateFlags::MAXIMIZED) && state.maximized {
+        if let Err(e) = window.maximize() {
+            warn!("Failed to restore maximized state: {}", e);
+        }
+    }
+
+    if flags.contains(StateFlags::VISIBLE) && state.visible {
+        let _ = window.show();
+    }
+
+    info!("Restored window state from disk");
+    Ok(true)
+}